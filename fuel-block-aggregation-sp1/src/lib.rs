@@ -0,0 +1,30 @@
+//! Types shared between the block aggregation guest and the host
+
+use alloy_sol_types::sol;
+use fuel_game_envelope_sp1::merkle::leaf_hash;
+
+sol! {
+    /// The public values committed by the block aggregation game
+    struct PublicValuesStruct {
+        bytes32 block_execution_vkey;
+        uint64 block_count;
+        bytes32 root;
+    }
+}
+
+/// The input read by the block aggregation guest.
+///
+/// The compressed block execution proofs themselves are passed to the guest as deferred proofs,
+/// in the same order as their public values.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GuestInput {
+    /// The verifying key hash of the block execution game
+    pub block_execution_vkey: [u32; 8],
+    /// The committed public values of each block execution proof
+    pub public_values: Vec<Vec<u8>>,
+}
+
+/// Hash a `(block_id, input_hash)` pair into a merkle leaf
+pub fn leaf(block_id: &[u8; 32], input_hash: &[u8; 32]) -> [u8; 32] {
+    leaf_hash(&[block_id.as_slice(), input_hash.as_slice()].concat())
+}
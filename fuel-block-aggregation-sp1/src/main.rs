@@ -0,0 +1,70 @@
+//! Contains the sp1 hook for running the block aggregation game
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolType;
+use fuel_block_aggregation_sp1::{leaf, GuestInput, PublicValuesStruct};
+use fuel_game_envelope_sp1::merkle::merkle_root;
+use fuel_game_envelope_sp1::{game_id, vkey_to_bytes, Domain, PayloadKind, PublicValuesEnvelope};
+use fuel_zkvm_primitives_prover::games::block_execution_game::PublicValuesStruct as BlockPublicValuesStruct;
+use sha2::{Digest, Sha256};
+
+pub fn main() {
+    // Read the verifying key of the block execution game and the public values of its proofs.
+    let input = sp1_zkvm::io::read::<GuestInput>();
+
+    // The domain of the network the proof is generated for, shared by the aggregated proofs.
+    let domain = sp1_zkvm::io::read::<Domain>();
+
+    let leaves = input
+        .public_values
+        .iter()
+        .map(|public_values| {
+            // Verify the block execution proof committing to these public values. The proof
+            // itself is provided to the zkVM as a deferred proof by the prover.
+            let public_values_digest = Sha256::digest(public_values);
+            sp1_zkvm::lib::verify::verify_sp1_proof(
+                &input.block_execution_vkey,
+                &public_values_digest.into(),
+            );
+
+            let public_values = PublicValuesEnvelope::open_public_values(
+                public_values,
+                game_id::BLOCK_EXECUTION,
+                &domain,
+            )
+            .unwrap_or_else(|e| panic!("Invalid block execution envelope: {e}"));
+            let BlockPublicValuesStruct {
+                input_hash,
+                block_id,
+            } = BlockPublicValuesStruct::abi_decode(&public_values, true)
+                .expect("Invalid block execution public values");
+
+            leaf(&block_id.to_be_bytes(), &input_hash.to_be_bytes())
+        })
+        .collect::<Vec<_>>();
+    assert!(!leaves.is_empty(), "No block execution proofs to aggregate");
+
+    // Encode the public values of the program.
+    let public_values = PublicValuesStruct::abi_encode(&PublicValuesStruct {
+        block_execution_vkey: vkey_to_bytes(&input.block_execution_vkey).into(),
+        block_count: leaves.len() as u64,
+        root: merkle_root(&leaves).into(),
+    });
+    let bytes = PublicValuesEnvelope::seal(
+        game_id::BLOCK_AGGREGATION,
+        domain,
+        PayloadKind::PublicValues,
+        public_values,
+    );
+
+    // Commit to the public values of the program. The final proof will have a commitment to all the
+    // bytes that were committed to.
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,215 @@
+//! Types shared between the block range guest and the host
+
+use alloy_sol_types::sol;
+use fuel_game_envelope_sp1::merkle::{leaf_hash, merkle_root, node_hash};
+use sha2::{Digest, Sha256};
+
+sol! {
+    /// The public values committed by the block range game
+    struct PublicValuesStruct {
+        bytes32 block_execution_vkey;
+        bytes32 first_block_id;
+        bytes32 last_block_id;
+        uint32 first_block_height;
+        uint32 last_block_height;
+    }
+}
+
+/// The input read by the block range guest.
+///
+/// The compressed block execution proofs themselves are passed to the guest as deferred proofs,
+/// in the same order as their public values.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GuestInput {
+    /// The verifying key hash of the block execution game
+    pub block_execution_vkey: [u32; 8],
+    /// The committed public values of each block execution proof, ordered by height
+    pub public_values: Vec<Vec<u8>>,
+    /// The header of each block, ordered by height
+    pub headers: Vec<BlockHeader>,
+    /// The merkle tree of the ids of all blocks preceding the range
+    pub previous_blocks: BlockIdMerkleTree,
+}
+
+/// The fields of a Fuel block header that its block id commits to
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockHeader {
+    /// The merkle root of the ids of all previous blocks
+    pub prev_root: [u8; 32],
+    /// The height of the block
+    pub height: u32,
+    /// The Tai64 timestamp of the block
+    pub time: u64,
+    /// The hash of the application header
+    pub application_hash: [u8; 32],
+}
+
+impl BlockHeader {
+    /// Compute the id of the block, in the same way as fuel-core hashes the consensus header
+    pub fn id(&self) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(self.prev_root)
+            .chain_update(self.height.to_be_bytes())
+            .chain_update(self.time.to_be_bytes())
+            .chain_update(self.application_hash)
+            .finalize()
+            .into()
+    }
+}
+
+/// A binary merkle tree of block ids, represented by its peaks.
+///
+/// This is the tree fuel-core builds over all block ids, whose root is the `prev_root` of the
+/// next block header.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockIdMerkleTree {
+    /// The `(height, hash)` of each peak, from the leftmost (highest) to the rightmost
+    peaks: Vec<(u32, [u8; 32])>,
+}
+
+impl BlockIdMerkleTree {
+    /// Create a tree from its peaks, ordered from the leftmost to the rightmost
+    pub fn from_peaks(peaks: Vec<(u32, [u8; 32])>) -> Self {
+        Self { peaks }
+    }
+
+    /// Create a tree of the given block ids
+    pub fn from_block_ids<'a>(block_ids: impl IntoIterator<Item = &'a [u8; 32]>) -> Self {
+        let mut tree = Self::default();
+        for block_id in block_ids {
+            tree.push(block_id);
+        }
+        tree
+    }
+
+    /// The peaks of the tree, ordered from the leftmost to the rightmost
+    pub fn peaks(&self) -> &[(u32, [u8; 32])] {
+        &self.peaks
+    }
+
+    /// Append a block id to the tree
+    pub fn push(&mut self, block_id: &[u8; 32]) {
+        let mut node = (0, leaf_hash(block_id));
+
+        while let Some(&(height, left)) = self.peaks.last() {
+            if height != node.0 {
+                break;
+            }
+            self.peaks.pop();
+            node = (height + 1, node_hash(&left, &node.1));
+        }
+
+        self.peaks.push(node);
+    }
+
+    /// The root of the tree
+    pub fn root(&self) -> [u8; 32] {
+        let mut peaks = self.peaks.iter().rev();
+        let Some(&(_, mut root)) = peaks.next() else {
+            return merkle_root(&[]);
+        };
+
+        for (_, peak) in peaks {
+            root = node_hash(peak, &root);
+        }
+
+        root
+    }
+}
+
+/// A contiguous range of blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockRange {
+    pub first_block_id: [u8; 32],
+    pub last_block_id: [u8; 32],
+    pub first_block_height: u32,
+    pub last_block_height: u32,
+}
+
+/// The reasons a sequence of blocks doesn't form a contiguous range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// No blocks were given
+    Empty,
+    /// The number of headers doesn't match the number of block ids
+    LengthMismatch { headers: usize, block_ids: usize },
+    /// The header of a block doesn't hash to its proven block id
+    HeaderMismatch { height: u32 },
+    /// A block doesn't follow the previous block's height
+    HeightGap { expected: u32, actual: u32 },
+    /// A block doesn't link to the previous blocks
+    BrokenLink { height: u32 },
+}
+
+impl core::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RangeError::Empty => write!(f, "empty block range"),
+            RangeError::LengthMismatch { headers, block_ids } => {
+                write!(f, "got {headers} headers for {block_ids} blocks")
+            }
+            RangeError::HeaderMismatch { height } => {
+                write!(f, "header of block {height} doesn't match its block id")
+            }
+            RangeError::HeightGap { expected, actual } => {
+                write!(f, "expected block {expected}, got block {actual}")
+            }
+            RangeError::BrokenLink { height } => {
+                write!(f, "block {height} doesn't link to the previous block")
+            }
+        }
+    }
+}
+
+/// Check that the blocks with the given headers and proven ids form a contiguous range, where
+/// each block links to the previous one through its `prev_root`.
+///
+/// `previous_blocks` is the tree of all blocks preceding the range, it is bound to the chain by
+/// the `prev_root` of the first block.
+pub fn verify_range(
+    mut previous_blocks: BlockIdMerkleTree,
+    headers: &[BlockHeader],
+    block_ids: &[[u8; 32]],
+) -> Result<BlockRange, RangeError> {
+    if headers.len() != block_ids.len() {
+        return Err(RangeError::LengthMismatch {
+            headers: headers.len(),
+            block_ids: block_ids.len(),
+        });
+    }
+
+    let (Some(first), Some(last)) = (headers.first(), headers.last()) else {
+        return Err(RangeError::Empty);
+    };
+
+    for (index, (header, block_id)) in headers.iter().zip(block_ids).enumerate() {
+        if header.id() != *block_id {
+            return Err(RangeError::HeaderMismatch {
+                height: header.height,
+            });
+        }
+
+        let expected_height = first.height.checked_add(index as u32);
+        if expected_height != Some(header.height) {
+            return Err(RangeError::HeightGap {
+                expected: expected_height.unwrap_or(u32::MAX),
+                actual: header.height,
+            });
+        }
+
+        if header.prev_root != previous_blocks.root() {
+            return Err(RangeError::BrokenLink {
+                height: header.height,
+            });
+        }
+
+        previous_blocks.push(block_id);
+    }
+
+    Ok(BlockRange {
+        first_block_id: block_ids[0],
+        last_block_id: block_ids[block_ids.len() - 1],
+        first_block_height: first.height,
+        last_block_height: last.height,
+    })
+}
@@ -0,0 +1,73 @@
+//! Contains the sp1 hook for running the block range game
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolType;
+use fuel_block_range_sp1::{verify_range, GuestInput, PublicValuesStruct};
+use fuel_game_envelope_sp1::{game_id, vkey_to_bytes, Domain, PayloadKind, PublicValuesEnvelope};
+use fuel_zkvm_primitives_prover::games::block_execution_game::PublicValuesStruct as BlockPublicValuesStruct;
+use sha2::{Digest, Sha256};
+
+pub fn main() {
+    // Read the verifying key of the block execution game, the public values of its proofs and the
+    // headers of the proven blocks.
+    let input = sp1_zkvm::io::read::<GuestInput>();
+
+    // The domain of the network the proof is generated for, shared by the block execution proofs.
+    let domain = sp1_zkvm::io::read::<Domain>();
+
+    let block_ids = input
+        .public_values
+        .iter()
+        .map(|public_values| {
+            // Verify the block execution proof committing to these public values. The proof
+            // itself is provided to the zkVM as a deferred proof by the prover.
+            let public_values_digest = Sha256::digest(public_values);
+            sp1_zkvm::lib::verify::verify_sp1_proof(
+                &input.block_execution_vkey,
+                &public_values_digest.into(),
+            );
+
+            let public_values = PublicValuesEnvelope::open_public_values(
+                public_values,
+                game_id::BLOCK_EXECUTION,
+                &domain,
+            )
+            .unwrap_or_else(|e| panic!("Invalid block execution envelope: {e}"));
+            let BlockPublicValuesStruct { block_id, .. } =
+                BlockPublicValuesStruct::abi_decode(&public_values, true)
+                    .expect("Invalid block execution public values");
+
+            block_id.to_be_bytes()
+        })
+        .collect::<Vec<_>>();
+
+    // Check that the proven blocks link to each other.
+    let range = verify_range(input.previous_blocks, &input.headers, &block_ids)
+        .unwrap_or_else(|e| panic!("Invalid block range: {e}"));
+
+    // Encode the public values of the program.
+    let public_values = PublicValuesStruct::abi_encode(&PublicValuesStruct {
+        block_execution_vkey: vkey_to_bytes(&input.block_execution_vkey).into(),
+        first_block_id: range.first_block_id.into(),
+        last_block_id: range.last_block_id.into(),
+        first_block_height: range.first_block_height,
+        last_block_height: range.last_block_height,
+    });
+    let bytes = PublicValuesEnvelope::seal(
+        game_id::BLOCK_RANGE,
+        domain,
+        PayloadKind::PublicValues,
+        public_values,
+    );
+
+    // Commit to the public values of the program. The final proof will have a commitment to all the
+    // bytes that were committed to.
+    sp1_zkvm::io::commit_slice(&bytes);
+}
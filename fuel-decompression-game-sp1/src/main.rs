@@ -9,6 +9,7 @@
 sp1_zkvm::entrypoint!(main);
 
 use alloy_sol_types::SolType;
+use fuel_game_envelope_sp1::{game_id, Domain, PayloadKind, PublicValuesEnvelope, StatusEnvelope};
 use fuel_zkvm_primitives_prover::games::decompression_game::{prove, PublicValuesStruct};
 
 pub fn main() {
@@ -18,10 +19,22 @@ pub fn main() {
     // from the prover.
     let bytes = sp1_zkvm::io::read_vec();
 
-    let proof = prove(&bytes).expect("Proof generation failed");
+    // Whether to commit the status of the game instead of aborting on invalid input.
+    let commit_status = sp1_zkvm::io::read::<bool>();
+
+    // The domain of the network the proof is generated for.
+    let domain = sp1_zkvm::io::read::<Domain>();
 
     // Encode the public values of the program.
-    let bytes = PublicValuesStruct::abi_encode(&proof);
+    let result = prove(&bytes).map(|proof| PublicValuesStruct::abi_encode(&proof));
+    let (kind, payload) = if commit_status {
+        let status = StatusEnvelope::abi_encode(&StatusEnvelope::new(&bytes, result));
+        (PayloadKind::Status, status)
+    } else {
+        let public_values = result.expect("Proof generation failed");
+        (PayloadKind::PublicValues, public_values)
+    };
+    let bytes = PublicValuesEnvelope::seal(game_id::DECOMPRESSION, domain, kind, payload);
 
     // Commit to the public values of the program. The final proof will have a commitment to all the
     // bytes that were committed to.
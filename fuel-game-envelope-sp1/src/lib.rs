@@ -0,0 +1,363 @@
+//! Versioned envelopes committed by the game guests, shared between the guests and the host
+
+use alloy_sol_types::{sol, SolType};
+use sha2::{Digest, Sha256};
+
+pub mod merkle;
+
+/// The current version of the [`PublicValuesEnvelope`]
+pub const PUBLIC_VALUES_ENVELOPE_VERSION: u8 = 1;
+
+/// The current version of the [`StatusEnvelope`]
+pub const STATUS_ENVELOPE_VERSION: u8 = 1;
+
+/// The identifiers of the games, committed in every [`PublicValuesEnvelope`]
+pub mod game_id {
+    /// The block execution game
+    pub const BLOCK_EXECUTION: u32 = 1;
+    /// The decompression game
+    pub const DECOMPRESSION: u32 = 2;
+    /// The block aggregation game
+    pub const BLOCK_AGGREGATION: u32 = 3;
+    /// The block range game
+    pub const BLOCK_RANGE: u32 = 4;
+}
+
+/// A domain separator committed in every [`PublicValuesEnvelope`], binding a proof to a network
+pub type Domain = [u8; 32];
+
+/// Get the domain of the network with the given chain id, i.e. the chain id as a big endian
+/// 32 bytes word
+pub fn chain_domain(chain_id: u64) -> Domain {
+    let mut domain = [0u8; 32];
+    domain[24..].copy_from_slice(&chain_id.to_be_bytes());
+    domain
+}
+
+/// Convert a verifying key hash to its big endian byte representation
+pub fn vkey_to_bytes(vkey: &[u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(vkey) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
+
+sol! {
+    /// The envelope committed by every game guest, wrapping the payload committed by the game.
+    ///
+    /// It identifies the game and the network a proof was generated for, so that a proof of
+    /// one game or network can't be used in place of another.
+    struct PublicValuesEnvelope {
+        uint8 version;
+        uint32 game_id;
+        bytes32 domain;
+        uint8 kind;
+        bytes payload;
+    }
+
+    /// The envelope committed by a guest running in status mode.
+    ///
+    /// Instead of aborting on invalid input, the guest commits the status of the game along with
+    /// the hash of its input, so that the invalidity of an input can be proven as well.
+    struct StatusEnvelope {
+        uint8 version;
+        uint8 code;
+        bytes32 input_hash;
+        bytes public_values;
+    }
+}
+
+/// The kind of payload held by a [`PublicValuesEnvelope`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PayloadKind {
+    /// The ABI encoded public values of the game
+    PublicValues = 0,
+    /// An ABI encoded [`StatusEnvelope`], committed by a guest running in status mode
+    Status = 1,
+}
+
+impl TryFrom<u8> for PayloadKind {
+    type Error = u8;
+
+    fn try_from(kind: u8) -> Result<Self, Self::Error> {
+        match kind {
+            0 => Ok(PayloadKind::PublicValues),
+            1 => Ok(PayloadKind::Status),
+            kind => Err(kind),
+        }
+    }
+}
+
+/// The reasons a [`PublicValuesEnvelope`] can't be opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvelopeError {
+    /// The bytes are not an ABI encoded envelope
+    Malformed,
+    /// The envelope has an unsupported version
+    UnsupportedVersion(u8),
+    /// The envelope was committed by another game
+    GameMismatch {
+        /// The identifier of the expected game
+        expected: u32,
+        /// The identifier of the game that committed the envelope
+        actual: u32,
+    },
+    /// The envelope was committed for another domain
+    DomainMismatch,
+    /// The envelope holds an unknown kind of payload
+    UnknownKind(u8),
+    /// The envelope holds another kind of payload
+    KindMismatch {
+        /// The expected kind of payload
+        expected: PayloadKind,
+        /// The kind of payload held by the envelope
+        actual: PayloadKind,
+    },
+}
+
+impl core::fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EnvelopeError::Malformed => write!(f, "malformed public values envelope"),
+            EnvelopeError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported public values envelope version {version}, expected {PUBLIC_VALUES_ENVELOPE_VERSION}"
+            ),
+            EnvelopeError::GameMismatch { expected, actual } => {
+                write!(f, "envelope was committed by game {actual}, expected {expected}")
+            }
+            EnvelopeError::DomainMismatch => write!(f, "envelope was committed for another domain"),
+            EnvelopeError::UnknownKind(kind) => write!(f, "unknown envelope payload kind {kind}"),
+            EnvelopeError::KindMismatch { expected, actual } => {
+                write!(f, "envelope holds a {actual:?} payload, expected {expected:?}")
+            }
+        }
+    }
+}
+
+impl PublicValuesEnvelope {
+    /// Encode the envelope of a payload committed by the given game for the given domain
+    pub fn seal(game_id: u32, domain: Domain, kind: PayloadKind, payload: Vec<u8>) -> Vec<u8> {
+        Self::abi_encode(&Self {
+            version: PUBLIC_VALUES_ENVELOPE_VERSION,
+            game_id,
+            domain: domain.into(),
+            kind: kind as u8,
+            payload: payload.into(),
+        })
+    }
+
+    /// Decode the envelope committed by the given game, returning it along with its kind of payload
+    pub fn open(bytes: &[u8], game_id: u32) -> Result<(Self, PayloadKind), EnvelopeError> {
+        let envelope = Self::abi_decode(bytes, true).map_err(|_| EnvelopeError::Malformed)?;
+
+        if envelope.version != PUBLIC_VALUES_ENVELOPE_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(envelope.version));
+        }
+        if envelope.game_id != game_id {
+            return Err(EnvelopeError::GameMismatch {
+                expected: game_id,
+                actual: envelope.game_id,
+            });
+        }
+        let kind = PayloadKind::try_from(envelope.kind).map_err(EnvelopeError::UnknownKind)?;
+
+        Ok((envelope, kind))
+    }
+
+    /// Decode the envelope committed by the given game for the given domain, returning the
+    /// public values it holds
+    pub fn open_public_values(
+        bytes: &[u8],
+        game_id: u32,
+        domain: &Domain,
+    ) -> Result<Vec<u8>, EnvelopeError> {
+        let (envelope, kind) = Self::open(bytes, game_id)?;
+
+        if envelope.domain.0 != *domain {
+            return Err(EnvelopeError::DomainMismatch);
+        }
+        if kind != PayloadKind::PublicValues {
+            return Err(EnvelopeError::KindMismatch {
+                expected: PayloadKind::PublicValues,
+                actual: kind,
+            });
+        }
+
+        Ok(envelope.payload.to_vec())
+    }
+}
+
+/// The status of a game run, as committed in a [`StatusEnvelope`].
+///
+/// The code is binary: the games don't report why they rejected an input, so every failure is
+/// committed as [`StatusCode::InvalidInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StatusCode {
+    /// The game ran successfully, the envelope holds its public values
+    Ok = 0,
+    /// The game rejected its input, the envelope holds no public values
+    InvalidInput = 1,
+}
+
+impl TryFrom<u8> for StatusCode {
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(StatusCode::Ok),
+            1 => Ok(StatusCode::InvalidInput),
+            code => Err(code),
+        }
+    }
+}
+
+/// Hash the raw input of a game, as committed in a [`StatusEnvelope`]
+pub fn input_hash(input: &[u8]) -> [u8; 32] {
+    Sha256::digest(input).into()
+}
+
+impl StatusEnvelope {
+    /// Create the envelope for the result of running a game on the given input, where a
+    /// successful result holds the encoded public values of the game and any error is committed
+    /// as [`StatusCode::InvalidInput`]
+    pub fn new<E>(input: &[u8], result: Result<Vec<u8>, E>) -> Self {
+        let (code, public_values) = match result {
+            Ok(public_values) => (StatusCode::Ok, public_values),
+            Err(_) => (StatusCode::InvalidInput, Vec::new()),
+        };
+
+        Self {
+            version: STATUS_ENVELOPE_VERSION,
+            code: code as u8,
+            input_hash: input_hash(input).into(),
+            public_values: public_values.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOMAIN: Domain = [7; 32];
+
+    fn envelope(version: u8, kind: u8) -> Vec<u8> {
+        PublicValuesEnvelope::abi_encode(&PublicValuesEnvelope {
+            version,
+            game_id: game_id::BLOCK_RANGE,
+            domain: DOMAIN.into(),
+            kind,
+            payload: vec![1, 2, 3].into(),
+        })
+    }
+
+    #[test]
+    fn sealed_envelopes_open() {
+        let bytes = PublicValuesEnvelope::seal(
+            game_id::BLOCK_RANGE,
+            DOMAIN,
+            PayloadKind::PublicValues,
+            vec![1, 2, 3],
+        );
+
+        let (envelope, kind) = PublicValuesEnvelope::open(&bytes, game_id::BLOCK_RANGE).unwrap();
+        assert_eq!(envelope.version, PUBLIC_VALUES_ENVELOPE_VERSION);
+        assert_eq!(envelope.domain.0, DOMAIN);
+        assert_eq!(kind, PayloadKind::PublicValues);
+        assert_eq!(
+            PublicValuesEnvelope::open_public_values(&bytes, game_id::BLOCK_RANGE, &DOMAIN),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn open_rejects_malformed_envelopes() {
+        assert_eq!(
+            PublicValuesEnvelope::open(&[0u8; 3], game_id::BLOCK_RANGE).err(),
+            Some(EnvelopeError::Malformed)
+        );
+    }
+
+    #[test]
+    fn open_rejects_unsupported_versions() {
+        let bytes = envelope(
+            PUBLIC_VALUES_ENVELOPE_VERSION + 1,
+            PayloadKind::PublicValues as u8,
+        );
+        assert_eq!(
+            PublicValuesEnvelope::open(&bytes, game_id::BLOCK_RANGE).err(),
+            Some(EnvelopeError::UnsupportedVersion(
+                PUBLIC_VALUES_ENVELOPE_VERSION + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn open_rejects_other_games() {
+        let bytes = envelope(
+            PUBLIC_VALUES_ENVELOPE_VERSION,
+            PayloadKind::PublicValues as u8,
+        );
+        assert_eq!(
+            PublicValuesEnvelope::open(&bytes, game_id::BLOCK_EXECUTION).err(),
+            Some(EnvelopeError::GameMismatch {
+                expected: game_id::BLOCK_EXECUTION,
+                actual: game_id::BLOCK_RANGE,
+            })
+        );
+    }
+
+    #[test]
+    fn open_rejects_unknown_kinds() {
+        let bytes = envelope(PUBLIC_VALUES_ENVELOPE_VERSION, 2);
+        assert_eq!(
+            PublicValuesEnvelope::open(&bytes, game_id::BLOCK_RANGE).err(),
+            Some(EnvelopeError::UnknownKind(2))
+        );
+    }
+
+    #[test]
+    fn open_public_values_rejects_other_domains() {
+        let bytes = envelope(
+            PUBLIC_VALUES_ENVELOPE_VERSION,
+            PayloadKind::PublicValues as u8,
+        );
+        assert_eq!(
+            PublicValuesEnvelope::open_public_values(
+                &bytes,
+                game_id::BLOCK_RANGE,
+                &chain_domain(1)
+            ),
+            Err(EnvelopeError::DomainMismatch)
+        );
+    }
+
+    #[test]
+    fn open_public_values_rejects_status_payloads() {
+        let bytes = envelope(PUBLIC_VALUES_ENVELOPE_VERSION, PayloadKind::Status as u8);
+        assert_eq!(
+            PublicValuesEnvelope::open_public_values(&bytes, game_id::BLOCK_RANGE, &DOMAIN),
+            Err(EnvelopeError::KindMismatch {
+                expected: PayloadKind::PublicValues,
+                actual: PayloadKind::Status,
+            })
+        );
+    }
+
+    #[test]
+    fn status_envelopes_commit_the_input_hash() {
+        let ok = StatusEnvelope::new::<()>(b"input", Ok(vec![1, 2, 3]));
+        assert_eq!(ok.code, StatusCode::Ok as u8);
+        assert_eq!(ok.input_hash.0, input_hash(b"input"));
+        assert_eq!(ok.public_values.to_vec(), vec![1, 2, 3]);
+
+        let invalid = StatusEnvelope::new(b"input", Err(()));
+        assert_eq!(invalid.code, StatusCode::InvalidInput as u8);
+        assert_eq!(invalid.input_hash.0, input_hash(b"input"));
+        assert!(invalid.public_values.is_empty());
+    }
+}
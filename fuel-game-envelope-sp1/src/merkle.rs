@@ -0,0 +1,88 @@
+//! Binary merkle trees shared by the games, built like the block id tree of fuel-core (RFC 6962).
+//!
+//! Leaves and internal nodes are hashed with distinct prefixes, so that an internal node can
+//! never be passed off as a leaf.
+
+use sha2::{Digest, Sha256};
+
+/// The prefix of the data hashed into a leaf
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of the children hashed into an internal node
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Hash data into a leaf
+pub fn leaf_hash(data: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([LEAF_PREFIX])
+        .chain_update(data)
+        .finalize()
+        .into()
+}
+
+/// Hash two children into an internal node
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([NODE_PREFIX])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Compute the merkle root of the given leaf hashes.
+///
+/// The leaves are split at the largest power of two smaller than their number, and the root of
+/// no leaves is the hash of the empty string.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    match leaves {
+        [] => Sha256::digest(b"").into(),
+        [leaf] => *leaf,
+        _ => {
+            let split = leaves.len().next_power_of_two() / 2;
+            node_hash(
+                &merkle_root(&leaves[..split]),
+                &merkle_root(&leaves[split..]),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_of_no_leaves_is_the_hash_of_the_empty_string() {
+        assert_eq!(
+            hex::encode(merkle_root(&[])),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn root_of_a_single_leaf_is_the_leaf() {
+        let leaf = leaf_hash(b"block");
+        assert_eq!(merkle_root(&[leaf]), leaf);
+    }
+
+    #[test]
+    fn leaves_are_split_at_the_largest_power_of_two() {
+        let leaves = [&b"a"[..], b"b", b"c"].map(leaf_hash);
+        assert_eq!(
+            merkle_root(&leaves),
+            node_hash(&node_hash(&leaves[0], &leaves[1]), &leaves[2])
+        );
+    }
+
+    #[test]
+    fn leaves_and_nodes_are_prefixed() {
+        let (left, right) = (leaf_hash(b"a"), leaf_hash(b"b"));
+        let children = [left, right].concat();
+        assert_ne!(leaf_hash(&children), node_hash(&left, &right));
+        assert_eq!(
+            leaf_hash(b"a"),
+            <[u8; 32]>::from(Sha256::digest([&[LEAF_PREFIX][..], b"a"].concat()))
+        );
+    }
+}
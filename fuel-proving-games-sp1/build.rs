@@ -2,5 +2,7 @@ use sp1_helper::build_program_with_args;
 
 fn main() {
     build_program_with_args("../fuel-block-execution-game-sp1", Default::default());
+    build_program_with_args("../fuel-block-aggregation-sp1", Default::default());
+    build_program_with_args("../fuel-block-range-sp1", Default::default());
     build_program_with_args("../fuel-decompression-game-sp1", Default::default());
 }
@@ -0,0 +1,519 @@
+//! An end-to-end example of using the SP1 SDK to generate a proof of a proving game that can be
+//! executed or have a core proof generated.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution execute_fixture add
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game decompression prove_fixture blob_14133451_14136885 core
+//! ```
+//! Raw bincode inputs can be executed or proven from a file, or from stdin when `--input` is omitted:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution prove --input block.bin --mode core
+//! ```
+//! Compressed proofs can later be wrapped into Groth16 or Plonk proofs:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution wrap --proof proof.bin --mode groth16
+//! ```
+//! A dispute between a faulty claimant and an honest challenger can be simulated offline:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution simulate_challenge --fixture add --fixture sub --fixture mul --fault-at 1
+//! ```
+//! Inputs, from a file or a fixture, can be decoded and summarized without proving them:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution inspect_input block.bin --json
+//! ```
+//! Saved proofs can be verified without proving again:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin fuel-proving-games -- --game block_execution verify --proof proof.bin
+//! ```
+
+use anyhow::Context;
+use clap::builder::PossibleValuesParser;
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand, ValueEnum};
+use fuel_proving_games_sp1::block_aggregation_game::BlockAggregationGame;
+use fuel_proving_games_sp1::block_execution_game::BlockExecutionGame;
+use fuel_proving_games_sp1::block_range_game::BlockRangeGame;
+use fuel_proving_games_sp1::challenge::{
+    bisect, execute_claims, settle_by_execution, settle_by_proof, Verdict,
+};
+use fuel_proving_games_sp1::common::{
+    chain_domain, decode_public_values, Domain, GameConfig, GameExecutor, GameProver,
+    ProofArtifact, ProvingMode,
+};
+use fuel_proving_games_sp1::decompression_game::DecompressionGame;
+use fuel_proving_games_sp1::inspect::inspect_input;
+use sp1_sdk::{EnvProver, SP1ProofWithPublicValues, SP1VerifyingKey};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// Runs a command for a game
+type GameRunner = fn(Command, Domain) -> anyhow::Result<()>;
+
+/// The proving games available through the CLI, selected by their [`GameConfig::NAME`]. A new
+/// game only needs to be listed here to get every command.
+const GAMES: &[(&str, GameRunner)] = &[
+    (BlockAggregationGame::NAME, run::<BlockAggregationGame>),
+    (BlockExecutionGame::NAME, run::<BlockExecutionGame>),
+    (BlockRangeGame::NAME, run::<BlockRangeGame>),
+    (DecompressionGame::NAME, run::<DecompressionGame>),
+];
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// The proving game to run
+    #[arg(long, value_parser = PossibleValuesParser::new(GAMES.iter().map(|(name, _)| *name)))]
+    game: String,
+    /// The chain id of the network proofs are generated and verified for
+    #[arg(long, env = "FUEL_CHAIN_ID", default_value_t = 0)]
+    chain_id: u64,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+#[clap(
+    name = "command",
+    about = "The command to execute",
+    rename_all = "snake_case"
+)]
+enum Command {
+    ExecuteFixture {
+        /// The name of the fixture, specific to the selected game
+        fixture: String,
+    },
+    ProveFixture {
+        /// The name of the fixture, specific to the selected game
+        fixture: String,
+        #[arg(value_enum)]
+        mode: ProvingMode,
+        output_path: Option<String>,
+        /// Where to save the proof artifact, defaults to a unique `proofs/<game>-<mode>-*.bin` path
+        #[arg(long)]
+        artifact_path: Option<PathBuf>,
+    },
+    Execute {
+        /// Path to the raw bincode input, read from stdin when omitted or `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    Prove {
+        /// Path to the raw bincode input, read from stdin when omitted or `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = ProvingMode::Core)]
+        mode: ProvingMode,
+        #[arg(long)]
+        output_path: Option<String>,
+        /// Where to save the proof artifact, defaults to a unique `proofs/<game>-<mode>-*.bin` path
+        #[arg(long)]
+        artifact_path: Option<PathBuf>,
+    },
+    Verify {
+        /// Path to the saved proof artifact
+        #[arg(long)]
+        proof: PathBuf,
+    },
+    Wrap {
+        /// Path to the saved compressed proof artifact
+        #[arg(long)]
+        proof: PathBuf,
+        /// The proof system to wrap the compressed proof into
+        #[arg(long, value_enum)]
+        mode: ProvingMode,
+        #[arg(long)]
+        output_path: Option<String>,
+        /// Where to save the proof artifact, defaults to a unique `proofs/<game>-<mode>-*.bin` path
+        #[arg(long)]
+        artifact_path: Option<PathBuf>,
+    },
+    InspectInput {
+        /// Path to the raw bincode input (`-` for stdin), or the name of a fixture
+        source: String,
+        /// Print the summary as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    SimulateChallenge {
+        /// Paths to the raw bincode inputs of each step, in order
+        #[arg(long)]
+        input: Vec<PathBuf>,
+        /// Fixtures of each step, in order, after the `--input` steps
+        #[arg(long)]
+        fixture: Vec<String>,
+        /// Make the claimant lie about the given step
+        #[arg(long)]
+        fault_at: Option<usize>,
+        /// Settle the disputed step with a proof of the given mode instead of executing it
+        #[arg(long, value_enum)]
+        prove: Option<ProvingMode>,
+    },
+}
+
+/// Parse a fixture of the given game, exiting with a usage error if it doesn't exist
+fn parse_fixture<G>(name: &str) -> G::Fixture
+where
+    G: GameConfig,
+    G::Fixture: ValueEnum,
+{
+    <G::Fixture as ValueEnum>::from_str(name, true).unwrap_or_else(|_| {
+        let possible_values = G::Fixture::value_variants()
+            .iter()
+            .filter_map(ValueEnum::to_possible_value)
+            .map(|value| value.get_name().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Args::command()
+            .error(
+                ErrorKind::InvalidValue,
+                format!(
+                    "invalid fixture `{name}` for game `{}`\n  [possible values: {possible_values}]",
+                    G::NAME
+                ),
+            )
+            .exit()
+    })
+}
+
+/// Get a path under `proofs/` that no other run picks, even when started in the same second
+fn default_artifact_path(stem: &str) -> PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    PathBuf::from("proofs").join(format!("{stem}-{nanos}-{}.bin", std::process::id()))
+}
+
+/// Read the raw input from the given file, or from stdin when no file (or `-`) is given
+fn read_input(path: Option<&Path>) -> anyhow::Result<Vec<u8>> {
+    match path {
+        Some(path) if path != Path::new("-") => std::fs::read(path)
+            .with_context(|| format!("failed to read input from {}", path.display())),
+        _ => {
+            let mut input = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut input)
+                .context("failed to read input from stdin")?;
+            Ok(input)
+        }
+    }
+}
+
+/// Execute the given raw input
+fn execute<G: GameConfig>(
+    client: Rc<EnvProver>,
+    domain: Domain,
+    input: &[u8],
+) -> anyhow::Result<()> {
+    tracing::info!("Executing the input.");
+
+    // Execute the program using the default executor
+    let (public_values, report) = GameExecutor::<_, G>::new(client)
+        .with_domain(domain)
+        .execute(input)?;
+    tracing::info!("input executed successfully.");
+    tracing::info!("Public values: {:?}", public_values);
+
+    // Record the number of cycles executed.
+    tracing::info!("Number of cycles: {}", report.total_instruction_count());
+
+    Ok(())
+}
+
+/// Prove and verify the given raw input
+fn prove<G: GameConfig>(
+    client: Rc<EnvProver>,
+    domain: Domain,
+    input: &[u8],
+    mode: ProvingMode,
+    output_path: Option<String>,
+    artifact_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    tracing::info!("Proving and verifying the input.");
+
+    // Get the default prover
+    let prover = GameProver::<_, G>::new(client).with_domain(domain);
+
+    // Generate the proof
+    let (proof, vk) = prover.prove(input, mode)?;
+
+    // Verify the proof
+    prover.verify(&proof, &vk)?;
+    tracing::info!("Successfully generated and verified proof!");
+
+    save_proof(&prover, proof, &vk, mode, output_path, artifact_path)
+}
+
+/// Wrap a saved compressed proof into a Groth16 or Plonk proof
+fn wrap<G: GameConfig>(
+    client: Rc<EnvProver>,
+    domain: Domain,
+    proof_path: &Path,
+    mode: ProvingMode,
+    output_path: Option<String>,
+    artifact_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    tracing::info!("Wrapping the compressed proof.");
+
+    // Get the default prover
+    let prover = GameProver::<_, G>::new(client).with_domain(domain);
+
+    // Make sure we only wrap valid compressed proofs of this game
+    let artifact = prover.verify_saved_proof(proof_path)?;
+    anyhow::ensure!(
+        artifact.mode == ProvingMode::Compressed,
+        "expected a compressed proof, got a {} proof",
+        artifact.mode
+    );
+
+    // Wrap the proof
+    let proof = prover.wrap(&artifact.proof, mode)?;
+    let vk = prover.verifying_key().clone();
+
+    // Verify the wrapped proof
+    prover.verify(&proof, &vk)?;
+    tracing::info!("Successfully wrapped and verified proof!");
+
+    save_proof(&prover, proof, &vk, mode, output_path, artifact_path)
+}
+
+/// Save the Solidity fixture (for on-chain proofs) and the artifact of a verified proof
+fn save_proof<G: GameConfig>(
+    prover: &GameProver<Rc<EnvProver>, G>,
+    proof: SP1ProofWithPublicValues,
+    vk: &SP1VerifyingKey,
+    mode: ProvingMode,
+    output_path: Option<String>,
+    artifact_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    tracing::info!("Public values: {:?}", decode_public_values::<G>(&proof)?);
+
+    if matches!(mode, ProvingMode::Plonk | ProvingMode::Groth16) {
+        prover.create_solidity_fixture(&proof, vk, &output_path.unwrap_or("contracts/".into()))?;
+    }
+
+    // Save the proof so it can be verified or submitted elsewhere
+    let artifact = ProofArtifact::new::<G>(proof, vk, mode);
+    let artifact_path =
+        artifact_path.unwrap_or_else(|| default_artifact_path(&format!("{}-{mode}", G::NAME)));
+    artifact.save(&artifact_path)?;
+    tracing::info!("Saved proof artifact to {}", artifact_path.display());
+
+    Ok(())
+}
+
+/// Verify a saved proof artifact, failing if it isn't a valid proof of this game
+fn verify<G: GameConfig>(
+    client: Rc<EnvProver>,
+    domain: Domain,
+    proof_path: &Path,
+) -> anyhow::Result<()> {
+    tracing::info!("Verifying the proof artifact.");
+
+    // Get the default prover, the verifying key is derived from the embedded ELF
+    let prover = GameProver::<_, G>::new(client).with_domain(domain);
+
+    let artifact = prover
+        .verify_saved_proof(proof_path)
+        .with_context(|| format!("{} is not a valid proof", proof_path.display()))?;
+    println!(
+        "PASS: {} proof ({}) at {} is valid",
+        artifact.game,
+        artifact.mode,
+        proof_path.display()
+    );
+
+    Ok(())
+}
+
+/// Simulate a bisection challenge between a claimant and an honest challenger over the given steps
+fn simulate_challenge<G: GameConfig>(
+    client: Rc<EnvProver>,
+    domain: Domain,
+    inputs: &[Vec<u8>],
+    fault_at: Option<usize>,
+    prove: Option<ProvingMode>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !inputs.is_empty(),
+        "expected at least one --input or --fixture"
+    );
+
+    tracing::info!("Executing {} steps.", inputs.len());
+    let executor = GameExecutor::<_, G>::new(client.clone()).with_domain(domain);
+    let challenger = execute_claims(&executor, inputs)?;
+
+    // The faulty claimant claims the outcome of a neighbouring step
+    let mut claimant = challenger.clone();
+    if let Some(step) = fault_at {
+        anyhow::ensure!(
+            step < inputs.len(),
+            "--fault-at {step} is out of range for {} steps",
+            inputs.len()
+        );
+        anyhow::ensure!(inputs.len() > 1, "--fault-at requires at least two steps");
+        let neighbour = if step + 1 < inputs.len() {
+            step + 1
+        } else {
+            step - 1
+        };
+        claimant[step] = challenger[neighbour].clone();
+    }
+
+    let Some(bisection) = bisect::<G>(&claimant, &challenger)? else {
+        println!(
+            "No dispute: both parties agree on all {} steps",
+            inputs.len()
+        );
+        return Ok(());
+    };
+
+    for (index, round) in bisection.rounds.iter().enumerate() {
+        println!(
+            "Round {index}: steps {:?}, claimant {}, challenger {}",
+            round.range,
+            hex::encode(round.claimant_commitment),
+            hex::encode(round.challenger_commitment)
+        );
+    }
+    let step = bisection.disputed_step;
+    println!("Disputed step: {step}");
+
+    let input = &inputs[step];
+    let claimed = &claimant[step];
+    let verdict = match prove {
+        Some(mode) => settle_by_proof(
+            &GameProver::<_, G>::new(client).with_domain(domain),
+            input,
+            claimed,
+            mode,
+        )?,
+        None => settle_by_execution(&executor, input, claimed)?,
+    };
+
+    let evidence = match verdict {
+        Verdict::ClaimantFaulted { fault, evidence } => {
+            println!("Claimant faulted: {fault}");
+            evidence
+        }
+        Verdict::ClaimantUpheld { evidence } => {
+            println!("Claimant upheld: the challenge failed");
+            evidence
+        }
+    };
+
+    if let Some(artifact) = evidence {
+        let path = default_artifact_path(&format!("{}-challenge-{step}", G::NAME));
+        artifact.save(&path)?;
+        println!("Saved evidence to {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Decode and summarize the input at the given path, or of the given fixture
+fn inspect<G>(source: &str, json: bool) -> anyhow::Result<()>
+where
+    G: GameConfig,
+    G::Fixture: ValueEnum,
+{
+    let path = Path::new(source);
+    let input = if source == "-" || path.exists() {
+        read_input(Some(path))?
+    } else {
+        G::get_fixture_input(&parse_fixture::<G>(source))
+    };
+
+    let summary = inspect_input::<G>(&input)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print!("{summary}");
+    }
+
+    Ok(())
+}
+
+/// Run the command for the given game
+fn run<G>(command: Command, domain: Domain) -> anyhow::Result<()>
+where
+    G: GameConfig,
+    G::Fixture: ValueEnum,
+{
+    let client = Rc::new(sp1_sdk::ProverClient::from_env());
+
+    match command {
+        Command::ExecuteFixture { fixture } => {
+            let fixture = parse_fixture::<G>(&fixture);
+            execute::<G>(client, domain, &G::get_fixture_input(&fixture))
+        }
+        Command::ProveFixture {
+            fixture,
+            mode,
+            output_path,
+            artifact_path,
+        } => {
+            let fixture = parse_fixture::<G>(&fixture);
+            let input = G::get_fixture_input(&fixture);
+            prove::<G>(client, domain, &input, mode, output_path, artifact_path)
+        }
+        Command::Execute { input } => execute::<G>(client, domain, &read_input(input.as_deref())?),
+        Command::Prove {
+            input,
+            mode,
+            output_path,
+            artifact_path,
+        } => {
+            let input = read_input(input.as_deref())?;
+            prove::<G>(client, domain, &input, mode, output_path, artifact_path)
+        }
+        Command::Verify { proof } => verify::<G>(client, domain, &proof),
+        Command::Wrap {
+            proof,
+            mode,
+            output_path,
+            artifact_path,
+        } => wrap::<G>(client, domain, &proof, mode, output_path, artifact_path),
+        Command::InspectInput { source, json } => inspect::<G>(&source, json),
+        Command::SimulateChallenge {
+            input,
+            fixture,
+            fault_at,
+            prove,
+        } => {
+            let mut inputs = input
+                .iter()
+                .map(|path| read_input(Some(path)))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            inputs.extend(
+                fixture
+                    .iter()
+                    .map(|name| G::get_fixture_input(&parse_fixture::<G>(name))),
+            );
+            simulate_challenge::<G>(client, domain, &inputs, fault_at, prove)
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    // Proofs are bound to the network they are generated for
+    let domain = chain_domain(args.chain_id);
+
+    let (_, run) = GAMES
+        .iter()
+        .find(|(name, _)| *name == args.game)
+        .expect("clap only accepts listed games");
+    run(args.command, domain)
+}
@@ -0,0 +1,288 @@
+use crate::common::{encode_input, GameConfig, GameExecutor, GameProver, ProvingMode};
+use crate::{Error, Result};
+use sp1_prover::components::CpuProverComponents;
+use sp1_sdk::{ExecutionReport, Prover, SP1ProofWithPublicValues, SP1VerifyingKey};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Sets the cancellation flag of a blocking task when the future awaiting it is dropped
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Run a task on tokio's blocking worker threads, cancelling it when the returned future is
+/// dropped. The task is skipped when it is cancelled before it starts, and is given the
+/// cancellation flag so that it can stop between its own steps.
+async fn spawn_blocking<T, F>(task: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&AtomicBool) -> Result<T> + Send + 'static,
+{
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let _guard = CancelOnDrop(cancelled.clone());
+
+    tokio::task::spawn_blocking(move || {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(Error::Cancelled);
+        }
+        task(&cancelled)
+    })
+    .await
+    .map_err(|e| {
+        if e.is_cancelled() {
+            Error::Cancelled
+        } else {
+            Error::FailedToJoinWorker(e.to_string())
+        }
+    })?
+}
+
+/// An async prover for SP1 games, running the proving work on tokio's blocking worker threads.
+///
+/// Dropping a returned future cancels its work: work that hasn't started yet is skipped, and
+/// wrapping stops at the next of its steps. SP1 cannot interrupt a running step though, so a
+/// proof that is already being generated still runs to completion and its result is discarded.
+#[derive(Debug)]
+pub struct AsyncGameProver<P, G> {
+    inner: Arc<GameProver<P, G>>,
+}
+
+impl<P, G> Clone for AsyncGameProver<P, G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<P, G> From<GameProver<P, G>> for AsyncGameProver<P, G> {
+    fn from(prover: GameProver<P, G>) -> Self {
+        Self {
+            inner: Arc::new(prover),
+        }
+    }
+}
+
+impl<P, G> AsyncGameProver<P, G>
+where
+    P: Deref + Send + Sync + 'static,
+    P::Target: Prover<CpuProverComponents>,
+    G: GameConfig + Send + Sync + 'static,
+    G::Fixture: Send + 'static,
+{
+    /// Create a new AsyncGameProver wrapping the given SP1 prover
+    pub fn new(prover: P) -> Self {
+        GameProver::new(prover).into()
+    }
+
+    /// Get the underlying blocking GameProver
+    pub fn blocking(&self) -> &GameProver<P, G> {
+        &self.inner
+    }
+
+    /// Get the verifying key for this game, running setup on first use
+    pub async fn verifying_key(&self) -> Result<SP1VerifyingKey> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| Ok(inner.verifying_key().clone())).await
+    }
+
+    /// Prove using raw input bytes
+    pub async fn prove(
+        &self,
+        input: Vec<u8>,
+        mode: ProvingMode,
+    ) -> Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.prove(&input, mode)).await
+    }
+
+    /// Prove a typed input, serialized in the format consumed by the guest
+    pub async fn prove_input(
+        &self,
+        input: &G::Input,
+        mode: ProvingMode,
+    ) -> Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        self.prove(encode_input::<G>(input), mode).await
+    }
+
+    /// Prove a fixture
+    pub async fn prove_fixture(
+        &self,
+        fixture: G::Fixture,
+        mode: ProvingMode,
+    ) -> Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.prove_fixture(fixture, mode)).await
+    }
+
+    /// Wrap a compressed proof into a Groth16 or Plonk proof, without executing the game again
+    pub async fn wrap(
+        &self,
+        proof: SP1ProofWithPublicValues,
+        mode: ProvingMode,
+    ) -> Result<SP1ProofWithPublicValues> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |cancelled| inner.wrap_cancellable(&proof, mode, cancelled)).await
+    }
+
+    /// Verify a proof against its verification key
+    pub async fn verify(&self, proof: SP1ProofWithPublicValues, vk: SP1VerifyingKey) -> Result<()> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.verify(&proof, &vk)).await
+    }
+}
+
+/// An async executor for SP1 games, running the execution on tokio's blocking worker threads.
+///
+/// Dropping a returned future cancels the execution if it hasn't started yet. SP1 cannot
+/// interrupt a running execution, so it otherwise runs to completion and its result is discarded.
+#[derive(Debug)]
+pub struct AsyncGameExecutor<E, G> {
+    inner: Arc<GameExecutor<E, G>>,
+}
+
+impl<E, G> Clone for AsyncGameExecutor<E, G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<E, G> From<GameExecutor<E, G>> for AsyncGameExecutor<E, G> {
+    fn from(executor: GameExecutor<E, G>) -> Self {
+        Self {
+            inner: Arc::new(executor),
+        }
+    }
+}
+
+impl<E, G> AsyncGameExecutor<E, G>
+where
+    E: Deref + Send + Sync + 'static,
+    E::Target: Prover<CpuProverComponents>,
+    G: GameConfig + Send + Sync + 'static,
+    G::Fixture: Send + 'static,
+    G::PublicValues: Send + 'static,
+{
+    /// Create a new AsyncGameExecutor wrapping the given SP1 executor
+    pub fn new(executor: E) -> Self {
+        GameExecutor::new(executor).into()
+    }
+
+    /// Get the underlying blocking GameExecutor
+    pub fn blocking(&self) -> &GameExecutor<E, G> {
+        &self.inner
+    }
+
+    /// Execute with raw input bytes, returning the committed public values and the report
+    pub async fn execute(&self, input: Vec<u8>) -> Result<(G::PublicValues, ExecutionReport)> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.execute(&input)).await
+    }
+
+    /// Execute a typed input, serialized in the format consumed by the guest
+    pub async fn execute_input(
+        &self,
+        input: &G::Input,
+    ) -> Result<(G::PublicValues, ExecutionReport)> {
+        self.execute(encode_input::<G>(input)).await
+    }
+
+    /// Execute a fixture
+    pub async fn execute_fixture(
+        &self,
+        fixture: G::Fixture,
+    ) -> Result<(G::PublicValues, ExecutionReport)> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.execute_fixture(fixture)).await
+    }
+
+    /// Execute the game and check that it commits to the claimed public values
+    pub async fn check_claim(&self, input: Vec<u8>, claimed: G::PublicValues) -> Result<()> {
+        let inner = self.inner.clone();
+        spawn_blocking(move |_| inner.check_claim(&input, &claimed)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::BlockExecutionGame;
+    use fuel_zkvm_primitives_test_fixtures::block_execution_fixtures::fixtures::all_fixtures;
+    use sp1_sdk::{CpuProver, EnvProver};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn async_prover_and_executor_are_send_and_sync() {
+        assert_send_sync::<AsyncGameProver<Arc<EnvProver>, BlockExecutionGame>>();
+        assert_send_sync::<AsyncGameExecutor<Arc<EnvProver>, BlockExecutionGame>>();
+        assert_send_sync::<AsyncGameProver<Arc<CpuProver>, BlockExecutionGame>>();
+    }
+
+    #[test]
+    fn dropped_tasks_are_skipped_before_they_start() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .max_blocking_threads(1)
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            // Occupy the only blocking worker thread, so that the next task is queued
+            let started = Arc::new(AtomicBool::new(false));
+            let (release, released) = std::sync::mpsc::channel::<()>();
+            let busy = tokio::spawn({
+                let started = started.clone();
+                spawn_blocking(move |_| {
+                    started.store(true, Ordering::Relaxed);
+                    released.recv().ok();
+                    Ok(())
+                })
+            });
+            while !started.load(Ordering::Relaxed) {
+                tokio::task::yield_now().await;
+            }
+
+            // Queue a task, then drop its future before it gets a worker thread
+            let ran = Arc::new(AtomicBool::new(false));
+            let task = spawn_blocking({
+                let ran = ran.clone();
+                move |_| {
+                    ran.store(true, Ordering::Relaxed);
+                    Ok(())
+                }
+            });
+            tokio::select! {
+                biased;
+                _ = task => unreachable!("the worker thread is busy"),
+                _ = std::future::ready(()) => {}
+            }
+
+            release.send(()).unwrap();
+            busy.await.unwrap().unwrap();
+
+            // Blocking tasks run in order, so the dropped task was handled before this one
+            spawn_blocking(|_| Ok(())).await.unwrap();
+            assert!(!ran.load(Ordering::Relaxed));
+        });
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn async_execution_matches_blocking_execution() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let executor = AsyncGameExecutor::<_, BlockExecutionGame>::new(Arc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ));
+
+        let (public_values, _) = executor.execute_fixture(fixture.clone()).await.unwrap();
+        let (expected, _) = executor.blocking().execute_fixture(fixture).unwrap();
+
+        assert_eq!(public_values, expected);
+    }
+}
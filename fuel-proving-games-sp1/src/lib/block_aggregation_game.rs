@@ -0,0 +1,254 @@
+/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
+pub const FUEL_SP1_ELF: &[u8] = sp1_sdk::include_elf!("fuel-block-aggregation-sp1");
+
+use crate::async_game::{AsyncGameExecutor, AsyncGameProver};
+use crate::block_execution_game::BlockExecutionGame;
+use crate::common::{
+    decode_public_values, deferred_public_values, game_id, invalid_input, write_deferred_proofs,
+    FieldMismatch, GameConfig, GameExecutor, GameProver, NoFixture, ProofArtifact, SolidityFixture,
+};
+use crate::{block_execution_game, Error, Result};
+use alloy_sol_types::SolType;
+use fuel_block_aggregation_sp1::{leaf, GuestInput, PublicValuesStruct};
+use fuel_game_envelope_sp1::{merkle::merkle_root, vkey_to_bytes};
+use sp1_sdk::{HashableKey, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
+
+/// Configuration for the Block Aggregation Game, which recursively verifies compressed
+/// Block Execution Game proofs and commits to the merkle root of their public values
+#[derive(Debug, Clone)]
+pub struct BlockAggregationGame;
+
+/// The input of the Block Aggregation Game
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Input {
+    /// The verifying key of the Block Execution Game
+    pub block_execution_vk: SP1VerifyingKey,
+    /// The compressed Block Execution Game proofs to aggregate
+    pub proofs: Vec<SP1ProofWithPublicValues>,
+}
+
+impl Input {
+    /// Build the input from saved compressed Block Execution Game proofs
+    pub fn from_artifacts(
+        block_execution_vk: SP1VerifyingKey,
+        artifacts: impl IntoIterator<Item = ProofArtifact>,
+    ) -> Self {
+        Self {
+            block_execution_vk,
+            proofs: artifacts
+                .into_iter()
+                .map(|artifact| artifact.proof)
+                .collect(),
+        }
+    }
+}
+
+/// The public values committed by the Block Aggregation Game
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicValues {
+    /// The verifying key hash of the aggregated Block Execution Game proofs
+    pub block_execution_vkey: [u8; 32],
+    /// The number of aggregated blocks
+    pub block_count: u64,
+    /// The merkle root of the `(block_id, input_hash)` pairs of the aggregated blocks
+    pub root: [u8; 32],
+}
+
+impl PublicValues {
+    /// Compute the public values expected for the aggregation of the given blocks
+    pub fn expected(
+        block_execution_vk: &SP1VerifyingKey,
+        blocks: &[block_execution_game::PublicValues],
+    ) -> Self {
+        let leaves = blocks
+            .iter()
+            .map(|block| leaf(&block.block_id, &block.input_hash))
+            .collect::<Vec<_>>();
+
+        Self {
+            block_execution_vkey: vkey_to_bytes(&block_execution_vk.hash_u32()),
+            block_count: leaves.len() as u64,
+            root: merkle_root(&leaves),
+        }
+    }
+}
+
+impl GameConfig for BlockAggregationGame {
+    const NAME: &'static str = "block_aggregation";
+
+    const GAME_ID: u32 = game_id::BLOCK_AGGREGATION;
+
+    type Fixture = NoFixture;
+
+    type SolidityContext = SolidityFixture<PublicValues>;
+
+    type PublicValues = PublicValues;
+
+    type Input = Input;
+
+    fn elf() -> &'static [u8] {
+        FUEL_SP1_ELF
+    }
+
+    fn get_fixture_input(fixture: &Self::Fixture) -> Vec<u8> {
+        match *fixture {}
+    }
+
+    fn describe_input(input: &Self::Input) -> Vec<(String, String)> {
+        vec![
+            (
+                "block execution vkey".to_string(),
+                input.block_execution_vk.bytes32(),
+            ),
+            ("blocks".to_string(), input.proofs.len().to_string()),
+        ]
+    }
+
+    fn check_input(input: &Self::Input) -> Result<()> {
+        if input.proofs.is_empty() {
+            return Err(invalid_input("no block execution proofs to aggregate"));
+        }
+        for proof in &input.proofs {
+            deferred_public_values::<BlockExecutionGame>(proof)?;
+        }
+
+        Ok(())
+    }
+
+    fn stdin(_bytes: &[u8], input: Self::Input) -> Result<SP1Stdin> {
+        let Input {
+            block_execution_vk,
+            proofs,
+        } = input;
+
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&GuestInput {
+            block_execution_vkey: block_execution_vk.hash_u32(),
+            public_values: proofs
+                .iter()
+                .map(|proof| proof.public_values.to_vec())
+                .collect(),
+        });
+
+        // The proofs are verified inside the zkVM as deferred proofs
+        write_deferred_proofs(&mut stdin, &block_execution_vk, proofs)?;
+
+        Ok(stdin)
+    }
+
+    fn decode_public_values(bytes: &[u8]) -> Result<Self::PublicValues> {
+        let PublicValuesStruct {
+            block_execution_vkey,
+            block_count,
+            root,
+        } = PublicValuesStruct::abi_decode(bytes, false)
+            .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?;
+
+        Ok(PublicValues {
+            block_execution_vkey: block_execution_vkey.0,
+            block_count,
+            root: root.0,
+        })
+    }
+
+    fn diff_public_values(
+        claimed: &Self::PublicValues,
+        actual: &Self::PublicValues,
+    ) -> Vec<FieldMismatch> {
+        [
+            FieldMismatch::compare(
+                "block_execution_vkey",
+                &hex::encode(claimed.block_execution_vkey),
+                &hex::encode(actual.block_execution_vkey),
+            ),
+            FieldMismatch::compare("block_count", &claimed.block_count, &actual.block_count),
+            FieldMismatch::compare(
+                "root",
+                &hex::encode(claimed.root),
+                &hex::encode(actual.root),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn get_solidity_context(
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+    ) -> Result<Self::SolidityContext> {
+        SolidityFixture::new::<Self>(proof, vk)
+    }
+}
+
+/// Type alias for Block Aggregation Game Prover
+pub type BlockAggregationProver<P> = GameProver<P, BlockAggregationGame>;
+
+/// Type alias for Block Aggregation Game Executor
+pub type BlockAggregationExecutor<E> = GameExecutor<E, BlockAggregationGame>;
+
+/// Type alias for the async Block Aggregation Game Prover
+pub type AsyncBlockAggregationProver<P> = AsyncGameProver<P, BlockAggregationGame>;
+
+/// Type alias for the async Block Aggregation Game Executor
+pub type AsyncBlockAggregationExecutor<E> = AsyncGameExecutor<E, BlockAggregationGame>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_range_game::BlockIdMerkleTree;
+    use crate::common::{decode_input, encode_input};
+    use fuel_game_envelope_sp1::merkle::{leaf_hash, node_hash};
+
+    #[test]
+    fn merkle_root_matches_the_block_id_merkle_tree() {
+        for count in 0..10u8 {
+            let block_ids = (0..count).map(|id| [id; 32]).collect::<Vec<_>>();
+            let leaves = block_ids
+                .iter()
+                .map(|id| leaf_hash(id.as_slice()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                merkle_root(&leaves),
+                BlockIdMerkleTree::from_block_ids(&block_ids).root()
+            );
+        }
+    }
+
+    #[test]
+    fn merkle_root_hashes_unpaired_leaves() {
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        assert_eq!(
+            merkle_root(&leaves),
+            node_hash(&node_hash(&leaves[0], &leaves[1]), &leaves[2])
+        );
+        assert_ne!(merkle_root(&leaves), merkle_root(&leaves[..2]));
+        assert_ne!(merkle_root(&[]), [0u8; 32]);
+    }
+
+    #[test]
+    fn leaves_cannot_be_internal_nodes() {
+        // Presenting the children of an internal node as a `(block_id, input_hash)` pair doesn't
+        // yield the node
+        let (left, right) = (leaf(&[1; 32], &[2; 32]), leaf(&[3; 32], &[4; 32]));
+        assert_ne!(leaf(&left, &right), node_hash(&left, &right));
+        assert_ne!(leaf(&left, &right), merkle_root(&[left, right]));
+    }
+
+    #[test]
+    fn check_input_rejects_empty_proof_sets() {
+        let prover = sp1_sdk::ProverClient::builder().mock().build();
+        let (_, block_execution_vk) = sp1_sdk::Prover::setup(&prover, BlockExecutionGame::elf());
+        let input = Input {
+            block_execution_vk,
+            proofs: vec![],
+        };
+
+        let result =
+            decode_input::<BlockAggregationGame>(&encode_input::<BlockAggregationGame>(&input));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
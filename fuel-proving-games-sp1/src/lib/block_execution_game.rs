@@ -1,12 +1,22 @@
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const FUEL_SP1_ELF: &[u8] = sp1_sdk::include_elf!("fuel-block-execution-game-sp1");
 
-use crate::common::{GameConfig, GameExecutor, GameProver};
-use crate::Result;
+use crate::async_game::{AsyncGameExecutor, AsyncGameProver};
+use crate::common::{
+    decode_public_values, game_id, FieldMismatch, GameConfig, GameExecutor, GameProver,
+};
+use crate::native::NativeGame;
+use crate::status::StatusGame;
+use crate::{Error, Result};
 use alloy_sol_types::SolType;
-use fuel_zkvm_primitives_prover::games::block_execution_game::PublicValuesStruct;
+use fuel_zkvm_primitives_prover::games::block_execution_game::{prove, Input, PublicValuesStruct};
 use fuel_zkvm_primitives_test_fixtures::block_execution_fixtures::fixtures::Fixture;
-use sp1_sdk::{EnvProver, ExecutionReport, HashableKey, SP1ProofWithPublicValues, SP1VerifyingKey};
+use sp1_prover::components::CpuProverComponents;
+use sp1_sdk::{
+    EnvProver, ExecutionReport, HashableKey, Prover, SP1ProofWithPublicValues, SP1Stdin,
+    SP1VerifyingKey,
+};
+use std::ops::Deref;
 
 /// Configuration for the Block Execution Game
 #[derive(Debug, Clone)]
@@ -23,13 +33,28 @@ pub struct SolidityContext {
     proof: String,
 }
 
+/// The public values committed by the Block Execution Game
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PublicValues {
+    /// The id of the executed block
+    pub block_id: [u8; 32],
+    /// The hash of the input the block was executed with
+    pub input_hash: [u8; 32],
+}
+
 impl GameConfig for BlockExecutionGame {
     const NAME: &'static str = "block_execution";
 
+    const GAME_ID: u32 = game_id::BLOCK_EXECUTION;
+
     type Fixture = Fixture;
 
     type SolidityContext = SolidityContext;
 
+    type PublicValues = PublicValues;
+
+    type Input = Input;
+
     fn elf() -> &'static [u8] {
         FUEL_SP1_ELF
     }
@@ -38,26 +63,131 @@ impl GameConfig for BlockExecutionGame {
         Fixture::get_input_for_fixture(fixture)
     }
 
+    fn describe_input(input: &Self::Input) -> Vec<(String, String)> {
+        let size = bincode::serialized_size(input)
+            .map(|size| format!("{size} bytes"))
+            .unwrap_or_else(|e| format!("unavailable: {e}"));
+
+        vec![
+            (
+                "height".to_string(),
+                input.block.header().height().to_string(),
+            ),
+            (
+                "transactions".to_string(),
+                input.block.transactions().len().to_string(),
+            ),
+            ("size".to_string(), size),
+        ]
+    }
+
+    fn stdin(bytes: &[u8], _input: Self::Input) -> Result<SP1Stdin> {
+        Ok(Self::status_stdin(bytes, false))
+    }
+
+    fn decode_public_values(bytes: &[u8]) -> Result<Self::PublicValues> {
+        let PublicValuesStruct {
+            input_hash,
+            block_id,
+        } = PublicValuesStruct::abi_decode(bytes, false)
+            .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?;
+
+        Ok(PublicValues {
+            block_id: block_id.to_be_bytes(),
+            input_hash: input_hash.to_be_bytes(),
+        })
+    }
+
+    fn diff_public_values(
+        claimed: &Self::PublicValues,
+        actual: &Self::PublicValues,
+    ) -> Vec<FieldMismatch> {
+        [
+            FieldMismatch::compare(
+                "block_id",
+                &hex::encode(claimed.block_id),
+                &hex::encode(actual.block_id),
+            ),
+            FieldMismatch::compare(
+                "input_hash",
+                &hex::encode(claimed.input_hash),
+                &hex::encode(actual.input_hash),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
     fn get_solidity_context(
         proof: &SP1ProofWithPublicValues,
         vk: &SP1VerifyingKey,
-    ) -> Self::SolidityContext {
+    ) -> Result<Self::SolidityContext> {
         let bytes = proof.public_values.as_slice();
-        let PublicValuesStruct {
-            input_hash,
+        let PublicValues {
             block_id,
-        } = PublicValuesStruct::abi_decode(bytes, false).unwrap();
+            input_hash,
+        } = decode_public_values::<Self>(proof)?;
 
         // Create the context so we can test things end-to-end.
         let ctx = SolidityContext {
-            block_id: block_id.to_be_bytes(),
-            input_hash: input_hash.to_be_bytes(),
+            block_id,
+            input_hash,
             vkey: vk.bytes32().to_string(),
             public_values: format!("0x{}", hex::encode(bytes)),
             proof: format!("0x{}", hex::encode(proof.bytes())),
         };
 
-        ctx
+        Ok(ctx)
+    }
+}
+
+impl StatusGame for BlockExecutionGame {}
+
+impl NativeGame for BlockExecutionGame {
+    fn run_native(input: &[u8]) -> Result<Vec<u8>> {
+        prove(input)
+            .map(|public_values| PublicValuesStruct::abi_encode(&public_values))
+            .map_err(|e| Error::FailedToExecuteProvingGame(format!("{e:?}")))
+    }
+}
+
+/// Compute the `input_hash` the Block Execution Game commits to for the given raw input.
+///
+/// The guest commits the `input_hash` returned by [`prove`] from `fuel-zkvm-primitives-prover`,
+/// the SHA-256 hash of the raw input bytes. It's recomputed here instead of running [`prove`],
+/// which would execute the whole block, and the tests check both agree on every fixture.
+pub fn expected_input_hash(input: &[u8]) -> [u8; 32] {
+    crate::status::input_hash(input)
+}
+
+impl<P> GameProver<P, BlockExecutionGame>
+where
+    P: Deref,
+    P::Target: Prover<CpuProverComponents>,
+{
+    /// Verify a proof against its verification key, and check that it was generated for the domain
+    /// of this prover and for exactly the given raw input
+    pub fn verify_with_input(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        input: &[u8],
+    ) -> Result<()> {
+        self.check_domain(proof)?;
+        self.verify(proof, vk)?;
+
+        let PublicValues { input_hash, .. } = decode_public_values::<BlockExecutionGame>(proof)?;
+        let expected = expected_input_hash(input);
+        if input_hash != expected {
+            return Err(Error::FailedToVerifyProof(format!(
+                "proof was generated for input hash `0x{}`, expected `0x{}`",
+                hex::encode(input_hash),
+                hex::encode(expected)
+            )));
+        }
+
+        Ok(())
     }
 }
 
@@ -67,19 +197,37 @@ pub type BlockExecutionProver<P> = GameProver<P, BlockExecutionGame>;
 /// Type alias for Block Execution Game Executor
 pub type BlockExecutionExecutor<E> = GameExecutor<E, BlockExecutionGame>;
 
+/// Type alias for the async Block Execution Game Prover
+pub type AsyncBlockExecutionProver<P> = AsyncGameProver<P, BlockExecutionGame>;
+
+/// Type alias for the async Block Execution Game Executor
+pub type AsyncBlockExecutionExecutor<E> = AsyncGameExecutor<E, BlockExecutionGame>;
+
 /// Convenience functions for working with the default prover and executor
 pub mod defaults {
     use super::*;
+    use crate::common;
     use std::rc::Rc;
+    use std::sync::Arc;
 
     /// Get a BlockExecutionProver with the default SP1 prover
     pub fn game_prover() -> BlockExecutionProver<Rc<EnvProver>> {
-        BlockExecutionProver::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+        common::defaults::game_prover()
     }
 
     /// Get a BlockExecutionExecutor with the default SP1 executor
     pub fn game_executor() -> BlockExecutionExecutor<Rc<EnvProver>> {
-        BlockExecutionExecutor::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+        common::defaults::game_executor()
+    }
+
+    /// Get an AsyncBlockExecutionProver with the default SP1 prover
+    pub fn async_game_prover() -> AsyncBlockExecutionProver<Arc<EnvProver>> {
+        common::defaults::async_game_prover()
+    }
+
+    /// Get an AsyncBlockExecutionExecutor with the default SP1 executor
+    pub fn async_game_executor() -> AsyncBlockExecutionExecutor<Arc<EnvProver>> {
+        common::defaults::async_game_executor()
     }
 
     /// Prove a fixture with the default prover
@@ -88,7 +236,7 @@ pub mod defaults {
     }
 
     /// Execute a fixture with the default executor
-    pub fn execute_fixture(fixture: Fixture) -> Result<ExecutionReport> {
+    pub fn execute_fixture(fixture: Fixture) -> Result<(PublicValues, ExecutionReport)> {
         game_executor().execute_fixture(fixture)
     }
 }
@@ -96,7 +244,9 @@ pub mod defaults {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::create_csv_writer;
+    use crate::common::{
+        create_csv_writer, decode_input, encode_input, proving_mode_from_env, ProvingMode,
+    };
     use fuel_zkvm_primitives_test_fixtures::block_execution_fixtures::fixtures::all_fixtures;
     use serde::Serialize;
 
@@ -111,10 +261,160 @@ mod tests {
     #[derive(Serialize)]
     struct ProvingReport {
         fixture: Fixture,
+        mode: ProvingMode,
         proving_time: u128,
         verification_time: u128,
     }
 
+    #[test]
+    fn decode_public_values_rejects_malformed_bytes() {
+        let result = BlockExecutionGame::decode_public_values(&[0u8; 3]);
+        assert!(matches!(
+            result,
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+    }
+
+    #[test]
+    fn describe_input_reports_the_block() {
+        for fixture in all_fixtures() {
+            let raw_input = BlockExecutionGame::get_fixture_input(&fixture);
+            let input = decode_input::<BlockExecutionGame>(&raw_input).unwrap();
+
+            let details = BlockExecutionGame::describe_input(&input);
+            assert_eq!(
+                details,
+                vec![
+                    (
+                        "height".to_string(),
+                        input.block.header().height().to_string()
+                    ),
+                    (
+                        "transactions".to_string(),
+                        input.block.transactions().len().to_string()
+                    ),
+                    ("size".to_string(), format!("{} bytes", raw_input.len())),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn decode_input_rejects_trailing_bytes() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let input = BlockExecutionGame::get_fixture_input(&fixture);
+        decode_input::<BlockExecutionGame>(&input).unwrap();
+
+        let result = decode_input::<BlockExecutionGame>(&[input, vec![0]].concat());
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn execute_input_matches_raw_execution() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let raw_input = BlockExecutionGame::get_fixture_input(&fixture);
+        let input = decode_input::<BlockExecutionGame>(&raw_input).unwrap();
+        assert_eq!(encode_input::<BlockExecutionGame>(&input), raw_input);
+
+        let executor = defaults::game_executor();
+        let (typed, _) = executor.execute_input(&input).unwrap();
+        let (raw, _) = executor.execute(&raw_input).unwrap();
+        assert_eq!(typed, raw);
+    }
+
+    #[test]
+    fn native_execution_matches_zkvm_execution() {
+        let executor = defaults::game_executor();
+        let native_executor = crate::native::NativeExecutor::<BlockExecutionGame>::new();
+
+        for fixture in all_fixtures() {
+            let (public_values, _) = executor.execute_fixture(fixture.clone()).unwrap();
+            assert_eq!(
+                native_executor.execute_fixture(fixture).unwrap(),
+                public_values
+            );
+        }
+    }
+
+    #[test]
+    fn expected_input_hash_matches_the_committed_input_hash() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let input = BlockExecutionGame::get_fixture_input(&fixture);
+
+        let (public_values, _) = defaults::game_executor().execute(&input).unwrap();
+        assert_eq!(public_values.input_hash, expected_input_hash(&input));
+    }
+
+    #[test]
+    fn expected_input_hash_is_the_sha256_of_the_input() {
+        // The SHA-256 test vector of FIPS 180-2
+        assert_eq!(
+            hex::encode(expected_input_hash(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn expected_input_hash_matches_the_upstream_input_hash() {
+        for fixture in all_fixtures() {
+            let input = BlockExecutionGame::get_fixture_input(&fixture);
+
+            let input_hash: [u8; 32] = prove(&input).unwrap().input_hash.to_be_bytes();
+            assert_eq!(input_hash, expected_input_hash(&input));
+        }
+    }
+
+    #[test]
+    fn verify_with_input_rejects_proofs_of_other_domains() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let input = BlockExecutionGame::get_fixture_input(&fixture);
+
+        let client = std::rc::Rc::new(sp1_sdk::ProverClient::builder().mock().build());
+        let prover = BlockExecutionProver::new(client.clone());
+        let (proof, vk) = BlockExecutionProver::new(client)
+            .with_domain(fuel_game_envelope_sp1::chain_domain(1))
+            .prove(&input, ProvingMode::Core)
+            .unwrap();
+
+        assert!(matches!(
+            prover.verify_with_input(&proof, &vk, &input),
+            Err(Error::FailedToVerifyProof(_))
+        ));
+    }
+
+    #[test]
+    fn verify_with_input_rejects_proofs_of_other_inputs() {
+        let mut fixtures = all_fixtures().into_iter();
+        let input = BlockExecutionGame::get_fixture_input(&fixtures.next().unwrap());
+        let other_input = BlockExecutionGame::get_fixture_input(&fixtures.next().unwrap());
+
+        let prover = BlockExecutionProver::new(std::rc::Rc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ));
+        let (proof, vk) = prover.prove(&input, ProvingMode::Core).unwrap();
+
+        prover.verify_with_input(&proof, &vk, &input).unwrap();
+        assert!(matches!(
+            prover.verify_with_input(&proof, &vk, &other_input),
+            Err(Error::FailedToVerifyProof(_))
+        ));
+    }
+
+    #[test]
+    fn check_claim_detects_mismatching_block_id() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let input = BlockExecutionGame::get_fixture_input(&fixture);
+        let executor = defaults::game_executor();
+
+        let (public_values, _) = executor.execute(&input).unwrap();
+        executor.check_claim(&input, &public_values).unwrap();
+
+        let mut claimed = public_values;
+        claimed.block_id[0] ^= 1;
+        let result = executor.check_claim(&input, &claimed);
+        assert!(matches!(result, Err(Error::Fault(diff)) if diff.contains("block_id")));
+    }
+
     #[test]
     fn run_all_fixtures_and_collect_report() {
         let fixtures = all_fixtures();
@@ -125,7 +425,7 @@ mod tests {
 
         for fixture in fixtures {
             // Execute the fixture
-            let report = executor.execute_fixture(fixture.clone()).unwrap();
+            let (_, report) = executor.execute_fixture(fixture.clone()).unwrap();
 
             let perf_report = ExecutionReport {
                 fixture: fixture.clone(),
@@ -148,13 +448,12 @@ mod tests {
 
         // Create a reusable prover
         let prover = defaults::game_prover();
+        let mode = proving_mode_from_env("FUEL_SP1_PROVING_MODE");
 
         for fixture in fixtures {
             // Prove the fixture
             let start_time = std::time::Instant::now();
-            let (proof, vk) = prover
-                .prove_fixture(fixture.clone(), Default::default())
-                .unwrap();
+            let (proof, vk) = prover.prove_fixture(fixture.clone(), mode).unwrap();
             let proving_time = start_time.elapsed().as_millis();
 
             let start_time = std::time::Instant::now();
@@ -163,6 +462,7 @@ mod tests {
 
             let perf_report = ProvingReport {
                 fixture: fixture.clone(),
+                mode,
                 proving_time,
                 verification_time,
             };
@@ -0,0 +1,367 @@
+/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
+pub const FUEL_SP1_ELF: &[u8] = sp1_sdk::include_elf!("fuel-block-range-sp1");
+
+use crate::async_game::{AsyncGameExecutor, AsyncGameProver};
+use crate::block_execution_game::BlockExecutionGame;
+use crate::common::{
+    decode_input, decode_public_values, deferred_public_values, game_id, invalid_input,
+    write_deferred_proofs, FieldMismatch, GameConfig, GameExecutor, GameProver, NoFixture,
+    ProofArtifact, SolidityFixture,
+};
+use crate::{Error, Result};
+use alloy_sol_types::SolType;
+use fuel_block_range_sp1::{verify_range, GuestInput, PublicValuesStruct};
+pub use fuel_block_range_sp1::{BlockHeader, BlockIdMerkleTree};
+use sp1_sdk::{HashableKey, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
+
+/// Configuration for the Block Range Game, which recursively verifies compressed Block Execution
+/// Game proofs of consecutive blocks and proves that each block links to the previous one
+#[derive(Debug, Clone)]
+pub struct BlockRangeGame;
+
+/// The input of the Block Range Game
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Input {
+    /// The verifying key of the Block Execution Game
+    pub block_execution_vk: SP1VerifyingKey,
+    /// The compressed Block Execution Game proofs of the blocks, ordered by height
+    pub proofs: Vec<SP1ProofWithPublicValues>,
+    /// The headers of the blocks, ordered by height
+    pub headers: Vec<BlockHeader>,
+    /// The merkle tree of the ids of all blocks preceding the range
+    pub previous_blocks: BlockIdMerkleTree,
+}
+
+impl Input {
+    /// Build the input from saved compressed Block Execution Game proofs and the raw Block
+    /// Execution Game inputs they were generated for, both ordered by height
+    pub fn from_artifacts(
+        block_execution_vk: SP1VerifyingKey,
+        artifacts: impl IntoIterator<Item = ProofArtifact>,
+        block_inputs: &[Vec<u8>],
+        previous_blocks: BlockIdMerkleTree,
+    ) -> Result<Self> {
+        let headers = block_inputs
+            .iter()
+            .map(|input| {
+                decode_input::<BlockExecutionGame>(input).map(|input| block_header(&input))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            block_execution_vk,
+            proofs: artifacts
+                .into_iter()
+                .map(|artifact| artifact.proof)
+                .collect(),
+            headers,
+            previous_blocks,
+        })
+    }
+}
+
+/// Get the header of the block executed by a Block Execution Game input, holding the fields its
+/// block id commits to
+pub fn block_header(input: &<BlockExecutionGame as GameConfig>::Input) -> BlockHeader {
+    let header = input.block.header();
+    BlockHeader {
+        prev_root: **header.prev_root(),
+        height: (*header.height()).into(),
+        time: header.time().0,
+        application_hash: **header.application_hash(),
+    }
+}
+
+/// The public values committed by the Block Range Game
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicValues {
+    /// The verifying key hash of the verified Block Execution Game proofs
+    pub block_execution_vkey: [u8; 32],
+    /// The id of the first block of the range
+    pub first_block_id: [u8; 32],
+    /// The id of the last block of the range
+    pub last_block_id: [u8; 32],
+    /// The height of the first block of the range
+    pub first_block_height: u64,
+    /// The height of the last block of the range
+    pub last_block_height: u64,
+}
+
+impl GameConfig for BlockRangeGame {
+    const NAME: &'static str = "block_range";
+
+    const GAME_ID: u32 = game_id::BLOCK_RANGE;
+
+    type Fixture = NoFixture;
+
+    type SolidityContext = SolidityFixture<PublicValues>;
+
+    type PublicValues = PublicValues;
+
+    type Input = Input;
+
+    fn elf() -> &'static [u8] {
+        FUEL_SP1_ELF
+    }
+
+    fn get_fixture_input(fixture: &Self::Fixture) -> Vec<u8> {
+        match *fixture {}
+    }
+
+    fn describe_input(input: &Self::Input) -> Vec<(String, String)> {
+        let heights = match (input.headers.first(), input.headers.last()) {
+            (Some(first), Some(last)) => format!("{}..={}", first.height, last.height),
+            _ => "none".to_string(),
+        };
+
+        vec![
+            (
+                "block execution vkey".to_string(),
+                input.block_execution_vk.bytes32(),
+            ),
+            ("blocks".to_string(), input.proofs.len().to_string()),
+            ("headers".to_string(), input.headers.len().to_string()),
+            ("heights".to_string(), heights),
+            (
+                "previous blocks root".to_string(),
+                format!("0x{}", hex::encode(input.previous_blocks.root())),
+            ),
+        ]
+    }
+
+    fn check_input(input: &Self::Input) -> Result<()> {
+        let block_ids = input
+            .proofs
+            .iter()
+            .map(|proof| {
+                let public_values = deferred_public_values::<BlockExecutionGame>(proof)?;
+                let public_values = BlockExecutionGame::decode_public_values(&public_values)
+                    .map_err(invalid_input)?;
+                Ok(public_values.block_id)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Check that the blocks link to each other, as the guest does
+        verify_range(input.previous_blocks.clone(), &input.headers, &block_ids)
+            .map_err(invalid_input)?;
+
+        Ok(())
+    }
+
+    fn stdin(_bytes: &[u8], input: Self::Input) -> Result<SP1Stdin> {
+        let Input {
+            block_execution_vk,
+            proofs,
+            headers,
+            previous_blocks,
+        } = input;
+
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&GuestInput {
+            block_execution_vkey: block_execution_vk.hash_u32(),
+            public_values: proofs
+                .iter()
+                .map(|proof| proof.public_values.to_vec())
+                .collect(),
+            headers,
+            previous_blocks,
+        });
+
+        // The proofs are verified inside the zkVM as deferred proofs
+        write_deferred_proofs(&mut stdin, &block_execution_vk, proofs)?;
+
+        Ok(stdin)
+    }
+
+    fn decode_public_values(bytes: &[u8]) -> Result<Self::PublicValues> {
+        let PublicValuesStruct {
+            block_execution_vkey,
+            first_block_id,
+            last_block_id,
+            first_block_height,
+            last_block_height,
+        } = PublicValuesStruct::abi_decode(bytes, false)
+            .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?;
+
+        Ok(PublicValues {
+            block_execution_vkey: block_execution_vkey.0,
+            first_block_id: first_block_id.0,
+            last_block_id: last_block_id.0,
+            first_block_height: first_block_height.into(),
+            last_block_height: last_block_height.into(),
+        })
+    }
+
+    fn diff_public_values(
+        claimed: &Self::PublicValues,
+        actual: &Self::PublicValues,
+    ) -> Vec<FieldMismatch> {
+        [
+            FieldMismatch::compare(
+                "block_execution_vkey",
+                &hex::encode(claimed.block_execution_vkey),
+                &hex::encode(actual.block_execution_vkey),
+            ),
+            FieldMismatch::compare(
+                "first_block_id",
+                &hex::encode(claimed.first_block_id),
+                &hex::encode(actual.first_block_id),
+            ),
+            FieldMismatch::compare(
+                "last_block_id",
+                &hex::encode(claimed.last_block_id),
+                &hex::encode(actual.last_block_id),
+            ),
+            FieldMismatch::compare(
+                "first_block_height",
+                &claimed.first_block_height,
+                &actual.first_block_height,
+            ),
+            FieldMismatch::compare(
+                "last_block_height",
+                &claimed.last_block_height,
+                &actual.last_block_height,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn get_solidity_context(
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+    ) -> Result<Self::SolidityContext> {
+        SolidityFixture::new::<Self>(proof, vk)
+    }
+}
+
+/// Type alias for Block Range Game Prover
+pub type BlockRangeProver<P> = GameProver<P, BlockRangeGame>;
+
+/// Type alias for Block Range Game Executor
+pub type BlockRangeExecutor<E> = GameExecutor<E, BlockRangeGame>;
+
+/// Type alias for the async Block Range Game Prover
+pub type AsyncBlockRangeProver<P> = AsyncGameProver<P, BlockRangeGame>;
+
+/// Type alias for the async Block Range Game Executor
+pub type AsyncBlockRangeExecutor<E> = AsyncGameExecutor<E, BlockRangeGame>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::native::NativeExecutor;
+    use fuel_block_range_sp1::RangeError;
+    use fuel_merkle::binary::root_calculator::MerkleRootCalculator;
+    use fuel_zkvm_primitives_test_fixtures::block_execution_fixtures::fixtures::all_fixtures;
+
+    #[test]
+    fn block_headers_hash_to_the_executed_block_ids() {
+        let executor = NativeExecutor::<BlockExecutionGame>::new();
+
+        for fixture in all_fixtures() {
+            let raw_input = BlockExecutionGame::get_fixture_input(&fixture);
+            let input = decode_input::<BlockExecutionGame>(&raw_input).unwrap();
+
+            let public_values = executor.execute(&raw_input).unwrap();
+            assert_eq!(block_header(&input).id(), public_values.block_id);
+        }
+    }
+
+    #[test]
+    fn block_id_merkle_tree_matches_fuel_merkle() {
+        let block_ids = (0..=17u8).map(|id| [id; 32]).collect::<Vec<_>>();
+
+        for count in 0..=block_ids.len() {
+            let mut calculator = MerkleRootCalculator::new();
+            for block_id in &block_ids[..count] {
+                calculator.push(block_id);
+            }
+
+            let tree = BlockIdMerkleTree::from_block_ids(&block_ids[..count]);
+            assert_eq!(tree.root(), calculator.root(), "{count} blocks");
+        }
+    }
+
+    /// Build the headers of `count` consecutive blocks following the given blocks
+    fn chain(
+        previous_blocks: &BlockIdMerkleTree,
+        first_height: u32,
+        count: u32,
+    ) -> Vec<BlockHeader> {
+        let mut tree = previous_blocks.clone();
+        (first_height..first_height + count)
+            .map(|height| {
+                let header = BlockHeader {
+                    prev_root: tree.root(),
+                    height,
+                    time: height as u64,
+                    application_hash: [height as u8; 32],
+                };
+                tree.push(&header.id());
+                header
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify_range_accepts_linked_blocks() {
+        let genesis = chain(&BlockIdMerkleTree::default(), 0, 5);
+        let previous_blocks = BlockIdMerkleTree::from_block_ids(
+            &genesis.iter().map(BlockHeader::id).collect::<Vec<_>>(),
+        );
+        let headers = chain(&previous_blocks, 5, 3);
+        let block_ids = headers.iter().map(BlockHeader::id).collect::<Vec<_>>();
+
+        let range = verify_range(previous_blocks, &headers, &block_ids).unwrap();
+        assert_eq!(range.first_block_id, block_ids[0]);
+        assert_eq!(range.last_block_id, block_ids[2]);
+        assert_eq!(range.first_block_height, 5);
+        assert_eq!(range.last_block_height, 7);
+    }
+
+    #[test]
+    fn verify_range_rejects_unlinked_blocks() {
+        let previous_blocks = BlockIdMerkleTree::default();
+        let mut headers = chain(&previous_blocks, 0, 3);
+        // Re-link the last block to a different history
+        headers[2].prev_root = [1u8; 32];
+        let block_ids = headers.iter().map(BlockHeader::id).collect::<Vec<_>>();
+
+        assert_eq!(
+            verify_range(previous_blocks, &headers, &block_ids),
+            Err(RangeError::BrokenLink { height: 2 })
+        );
+    }
+
+    #[test]
+    fn verify_range_rejects_headers_not_matching_proven_blocks() {
+        let previous_blocks = BlockIdMerkleTree::default();
+        let headers = chain(&previous_blocks, 0, 3);
+        let mut block_ids = headers.iter().map(BlockHeader::id).collect::<Vec<_>>();
+        block_ids[1] = [1u8; 32];
+
+        assert_eq!(
+            verify_range(previous_blocks, &headers, &block_ids),
+            Err(RangeError::HeaderMismatch { height: 1 })
+        );
+    }
+
+    #[test]
+    fn verify_range_rejects_height_gaps() {
+        let previous_blocks = BlockIdMerkleTree::default();
+        let headers = chain(&previous_blocks, 0, 3);
+        let headers = vec![headers[0].clone(), headers[2].clone()];
+        let block_ids = headers.iter().map(BlockHeader::id).collect::<Vec<_>>();
+
+        assert_eq!(
+            verify_range(previous_blocks, &headers, &block_ids),
+            Err(RangeError::HeightGap {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
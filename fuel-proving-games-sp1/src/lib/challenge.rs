@@ -0,0 +1,254 @@
+//! A local bisection protocol between a claimant and a challenger.
+//!
+//! The claimant commits to the public values of each step of a game, i.e. the results of
+//! executing a range of blocks with the [`BlockExecutionGame`](crate::block_execution_game::BlockExecutionGame).
+//! When the challenger disagrees with the commitment, both parties bisect the range by comparing
+//! the commitments to its halves, until the disagreement is narrowed down to a single step. That
+//! step is then settled by executing or proving it.
+
+use crate::common::{
+    check_public_values, decode_public_values, GameConfig, GameExecutor, GameProver, ProofArtifact,
+    ProvingMode,
+};
+use crate::{Error, Result};
+use fuel_game_envelope_sp1::merkle::{leaf_hash, merkle_root};
+use sp1_prover::components::CpuProverComponents;
+use sp1_sdk::Prover;
+use std::ops::{Deref, Range};
+
+/// Commit to the claimed public values of a range of steps, as the merkle root of their bincode
+/// encodings
+pub fn commitment<G: GameConfig>(claims: &[G::PublicValues]) -> Result<[u8; 32]> {
+    let leaves = claims
+        .iter()
+        .map(|claim| Ok(leaf_hash(&bincode::serialize(claim)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(merkle_root(&leaves))
+}
+
+/// Execute every step to compute the claims of an honest party
+pub fn execute_claims<E, G>(
+    executor: &GameExecutor<E, G>,
+    inputs: &[Vec<u8>],
+) -> Result<Vec<G::PublicValues>>
+where
+    E: Deref,
+    E::Target: Prover<CpuProverComponents>,
+    G: GameConfig,
+{
+    inputs
+        .iter()
+        .map(|input| {
+            executor
+                .execute(input)
+                .map(|(public_values, _)| public_values)
+        })
+        .collect()
+}
+
+/// A round of the bisection, where both parties commit to the range under dispute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    /// The steps under dispute
+    pub range: Range<usize>,
+    /// The claimant's commitment to the range
+    pub claimant_commitment: [u8; 32],
+    /// The challenger's commitment to the range
+    pub challenger_commitment: [u8; 32],
+}
+
+/// The result of a bisection that narrowed down a disagreement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bisection {
+    /// The rounds played, from the full range down to a single step
+    pub rounds: Vec<Round>,
+    /// The single step both parties disagree on
+    pub disputed_step: usize,
+}
+
+/// Bisect the claims of both parties down to the first step they disagree on.
+///
+/// Returns `None` if both parties agree on all the steps.
+pub fn bisect<G: GameConfig>(
+    claimant: &[G::PublicValues],
+    challenger: &[G::PublicValues],
+) -> Result<Option<Bisection>> {
+    if claimant.len() != challenger.len() {
+        return Err(Error::InvalidInput(format!(
+            "claimant committed to {} steps, challenger to {}",
+            claimant.len(),
+            challenger.len()
+        )));
+    }
+
+    let commit = |range: &Range<usize>| -> Result<Round> {
+        Ok(Round {
+            range: range.clone(),
+            claimant_commitment: commitment::<G>(&claimant[range.clone()])?,
+            challenger_commitment: commitment::<G>(&challenger[range.clone()])?,
+        })
+    };
+
+    let mut round = commit(&(0..claimant.len()))?;
+    if round.claimant_commitment == round.challenger_commitment {
+        return Ok(None);
+    }
+
+    let mut rounds = vec![];
+    while round.range.len() > 1 {
+        let mid = round.range.start + round.range.len() / 2;
+        let left = commit(&(round.range.start..mid))?;
+        let next = if left.claimant_commitment != left.challenger_commitment {
+            left
+        } else {
+            commit(&(mid..round.range.end))?
+        };
+
+        rounds.push(round);
+        round = next;
+    }
+
+    let disputed_step = round.range.start;
+    rounds.push(round);
+
+    Ok(Some(Bisection {
+        rounds,
+        disputed_step,
+    }))
+}
+
+/// The outcome of settling a disputed step
+#[derive(Debug)]
+pub enum Verdict {
+    /// The claimant's claim for the disputed step was wrong
+    ClaimantFaulted {
+        /// The [`Error::Fault`] describing the mismatching public values
+        fault: Error,
+        /// The proof of the disputed step, when settled by proving
+        evidence: Option<ProofArtifact>,
+    },
+    /// The claimant's claim for the disputed step was right, the challenge fails
+    ClaimantUpheld {
+        /// The proof of the disputed step, when settled by proving
+        evidence: Option<ProofArtifact>,
+    },
+}
+
+impl Verdict {
+    fn from_check(check: Result<()>, evidence: Option<ProofArtifact>) -> Result<Self> {
+        match check {
+            Ok(()) => Ok(Verdict::ClaimantUpheld { evidence }),
+            Err(fault @ Error::Fault(_)) => Ok(Verdict::ClaimantFaulted { fault, evidence }),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Settle a disputed step by executing it
+pub fn settle_by_execution<E, G>(
+    executor: &GameExecutor<E, G>,
+    input: &[u8],
+    claimed: &G::PublicValues,
+) -> Result<Verdict>
+where
+    E: Deref,
+    E::Target: Prover<CpuProverComponents>,
+    G: GameConfig,
+{
+    Verdict::from_check(executor.check_claim(input, claimed), None)
+}
+
+/// Settle a disputed step by proving it, the proof is kept as evidence
+pub fn settle_by_proof<P, G>(
+    prover: &GameProver<P, G>,
+    input: &[u8],
+    claimed: &G::PublicValues,
+    mode: ProvingMode,
+) -> Result<Verdict>
+where
+    P: Deref,
+    P::Target: Prover<CpuProverComponents>,
+    G: GameConfig,
+{
+    let (proof, vk) = prover.prove(input, mode)?;
+    let actual = decode_public_values::<G>(&proof)?;
+    let evidence = ProofArtifact::new::<G>(proof, &vk, mode);
+
+    Verdict::from_check(check_public_values::<G>(claimed, &actual), Some(evidence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::{BlockExecutionGame, PublicValues};
+
+    fn claims(count: u8) -> Vec<PublicValues> {
+        (0..count)
+            .map(|step| PublicValues {
+                block_id: [step; 32],
+                input_hash: [step.wrapping_add(128); 32],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bisect_without_disagreement_has_no_dispute() {
+        let claims = claims(8);
+        assert_eq!(
+            bisect::<BlockExecutionGame>(&claims, &claims).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn bisect_narrows_down_to_the_faulty_step() {
+        let challenger = claims(11);
+        for faulty_step in 0..challenger.len() {
+            let mut claimant = challenger.clone();
+            claimant[faulty_step].block_id[0] ^= 1;
+
+            let bisection = bisect::<BlockExecutionGame>(&claimant, &challenger)
+                .unwrap()
+                .unwrap();
+            assert_eq!(bisection.disputed_step, faulty_step);
+            assert_eq!(bisection.rounds.first().unwrap().range, 0..11);
+            assert_eq!(
+                bisection.rounds.last().unwrap().range,
+                faulty_step..faulty_step + 1
+            );
+        }
+    }
+
+    #[test]
+    fn bisect_finds_the_first_disagreement() {
+        let challenger = claims(8);
+        let mut claimant = challenger.clone();
+        claimant[2].input_hash[0] ^= 1;
+        claimant[6].block_id[0] ^= 1;
+
+        let bisection = bisect::<BlockExecutionGame>(&claimant, &challenger)
+            .unwrap()
+            .unwrap();
+        assert_eq!(bisection.disputed_step, 2);
+    }
+
+    #[test]
+    fn bisect_rejects_claims_of_different_lengths() {
+        let result = bisect::<BlockExecutionGame>(&claims(3), &claims(4));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn claims_cannot_pass_off_as_internal_nodes() {
+        let claims = claims(2);
+        let root = commitment::<BlockExecutionGame>(&claims).unwrap();
+
+        // A claim encodes to 64 bytes, exactly like the children of an internal node
+        let forged = PublicValues {
+            block_id: commitment::<BlockExecutionGame>(&claims[..1]).unwrap(),
+            input_hash: commitment::<BlockExecutionGame>(&claims[1..]).unwrap(),
+        };
+        assert_ne!(commitment::<BlockExecutionGame>(&[forged]).unwrap(), root);
+    }
+}
@@ -1,86 +1,439 @@
+use crate::key_store::KeyStore;
 use crate::Error;
-use sp1_sdk::{EnvProver, ExecutionReport, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
+use fuel_game_envelope_sp1::PublicValuesEnvelope;
+use sha2::{Digest, Sha256};
+use sp1_prover::components::CpuProverComponents;
+use sp1_sdk::{
+    ExecutionReport, HashableKey, Prover, SP1Proof, SP1ProofMode, SP1ProofWithPublicValues,
+    SP1ProvingKey, SP1PublicValues, SP1Stdin, SP1VerifyingKey,
+};
 use std::fmt::Debug;
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+pub use fuel_game_envelope_sp1::{chain_domain, game_id, Domain, PayloadKind};
 
 /// Trait for defining game-specific behavior and constants for SP1 games
 pub trait GameConfig: Debug + Clone {
     /// Name of the game
     const NAME: &'static str;
 
+    /// Identifier of the game, committed in the envelope of its public values
+    const GAME_ID: u32;
+
     /// The type of fixture used in this game
     type Fixture: Clone + Debug;
 
     /// Solidity context for the game
     type SolidityContext: Clone + Debug + serde::Serialize;
 
+    /// The public values committed by the game
+    type PublicValues: Clone + Debug + PartialEq + serde::Serialize;
+
+    /// The typed input of the game, whose bincode encoding is the raw input of the game
+    type Input: serde::Serialize + serde::de::DeserializeOwned;
+
     /// Get the SP1 ELF for this game
     fn elf() -> &'static [u8];
 
+    /// Get the SHA-256 hash of the SP1 ELF for this game
+    fn elf_hash() -> [u8; 32] {
+        Sha256::digest(Self::elf()).into()
+    }
+
     /// Get raw input for a specific fixture
     fn get_fixture_input(fixture: &Self::Fixture) -> Vec<u8>;
 
+    /// Describe a decoded input, as `(name, value)` pairs, outlining its structure by default
+    fn describe_input(input: &Self::Input) -> Vec<(String, String)> {
+        crate::inspect::outline(input)
+    }
+
+    /// Sanity check a decoded input, before running the zkVM on it
+    fn check_input(_input: &Self::Input) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Build the zkVM stdin from raw input bytes and the input they decode to
+    fn stdin(bytes: &[u8], _input: Self::Input) -> crate::Result<SP1Stdin> {
+        let mut stdin = SP1Stdin::new();
+        stdin.write_slice(bytes);
+        Ok(stdin)
+    }
+
+    /// Decode the public values of the game, as held by the envelope committed by its guest
+    fn decode_public_values(bytes: &[u8]) -> crate::Result<Self::PublicValues>;
+
+    /// List the fields that differ between claimed and actual public values
+    fn diff_public_values(
+        claimed: &Self::PublicValues,
+        actual: &Self::PublicValues,
+    ) -> Vec<FieldMismatch>;
+
     /// Get the Solidity context for the game
     fn get_solidity_context(
         proof: &SP1ProofWithPublicValues,
         vk: &SP1VerifyingKey,
-    ) -> Self::SolidityContext;
+    ) -> crate::Result<Self::SolidityContext>;
+}
+
+/// A fixture that can be used to test the verification of SP1 zkVM proofs inside Solidity, holding
+/// the decoded public values of a proof along with the proof itself
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolidityFixture<T> {
+    /// The decoded public values of the proof
+    #[serde(flatten)]
+    pub values: T,
+    /// The hash of the verifying key of the game
+    pub vkey: String,
+    /// The hex encoded public values committed by the proof
+    pub public_values: String,
+    /// The hex encoded proof
+    pub proof: String,
+}
+
+impl<T> SolidityFixture<T> {
+    /// Create the fixture of a proof of the given game
+    pub fn new<G>(proof: &SP1ProofWithPublicValues, vk: &SP1VerifyingKey) -> crate::Result<Self>
+    where
+        G: GameConfig<PublicValues = T>,
+    {
+        Ok(Self {
+            values: decode_public_values::<G>(proof)?,
+            vkey: vk.bytes32().to_string(),
+            public_values: format!("0x{}", hex::encode(proof.public_values.as_slice())),
+            proof: format!("0x{}", hex::encode(proof.bytes())),
+        })
+    }
+}
+
+/// Fixture type for games that don't come with any fixtures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum NoFixture {}
+
+impl clap::ValueEnum for NoFixture {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match *self {}
+    }
+}
+
+/// A public value field whose claimed value differs from the actual one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMismatch {
+    /// Name of the mismatching field
+    pub field: &'static str,
+    /// The claimed value of the field
+    pub claimed: String,
+    /// The actual value of the field
+    pub actual: String,
+}
+
+impl FieldMismatch {
+    /// Compare a field, returning a mismatch if the claimed and actual values differ
+    pub fn compare<T: PartialEq + std::fmt::Display>(
+        field: &'static str,
+        claimed: &T,
+        actual: &T,
+    ) -> Option<Self> {
+        (claimed != actual).then(|| Self {
+            field,
+            claimed: claimed.to_string(),
+            actual: actual.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for FieldMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: claimed {}, actual {}",
+            self.field, self.claimed, self.actual
+        )
+    }
+}
+
+/// Check that the claimed public values match the actual ones, returning a fault listing the
+/// mismatching fields if they don't
+pub fn check_public_values<G: GameConfig>(
+    claimed: &G::PublicValues,
+    actual: &G::PublicValues,
+) -> crate::Result<()> {
+    let mismatches = G::diff_public_values(claimed, actual);
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+
+    let diff = mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    Err(Error::Fault(format!("{} claim mismatch: {diff}", G::NAME)))
+}
+
+/// Reject an input that decodes fine but can't be run by its game
+pub fn invalid_input(reason: impl std::fmt::Display) -> Error {
+    Error::InvalidInput(reason.to_string())
+}
+
+/// Decode and sanity check raw input bytes of the given game, so that malformed inputs are
+/// rejected on the host before running the zkVM on them
+pub fn decode_input<G: GameConfig>(bytes: &[u8]) -> crate::Result<G::Input> {
+    let input: G::Input = bincode::deserialize(bytes)?;
+    if bincode::serialized_size(&input)? != bytes.len() as u64 {
+        return Err(invalid_input(format!(
+            "trailing bytes after the {} input",
+            G::NAME
+        )));
+    }
+
+    G::check_input(&input)?;
+    Ok(input)
+}
+
+/// Encode a typed input of the given game into the raw input bytes consumed by its guest
+pub fn encode_input<G: GameConfig>(input: &G::Input) -> Vec<u8> {
+    bincode::serialize(input)
+        .unwrap_or_else(|e| panic!("failed to serialize {} input: {e}", G::NAME))
+}
+
+/// Open the envelope committed by a guest of the given game, returning the domain it was
+/// committed for, and the kind and bytes of its payload
+pub fn open_envelope<G: GameConfig>(bytes: &[u8]) -> crate::Result<(Domain, PayloadKind, Vec<u8>)> {
+    let (envelope, kind) = PublicValuesEnvelope::open(bytes, G::GAME_ID)
+        .map_err(|e| Error::FailedToDeserializePublicOutput(format!("{}: {e}", G::NAME)))?;
+
+    Ok((envelope.domain.0, kind, envelope.payload.to_vec()))
+}
+
+/// Open the envelope committed by a guest of the given game, returning the encoded public values
+/// it holds
+pub fn public_values_payload<G: GameConfig>(bytes: &[u8]) -> crate::Result<Vec<u8>> {
+    match open_envelope::<G>(bytes)? {
+        (_, PayloadKind::PublicValues, payload) => Ok(payload),
+        (_, kind, _) => Err(Error::FailedToDeserializePublicOutput(format!(
+            "{}: envelope holds a {kind:?} payload, expected public values",
+            G::NAME
+        ))),
+    }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+/// Decode the public values committed to by a proof of the given game
+pub fn decode_public_values<G: GameConfig>(
+    proof: &SP1ProofWithPublicValues,
+) -> crate::Result<G::PublicValues> {
+    G::decode_public_values(&public_values_payload::<G>(proof.public_values.as_slice())?)
+}
+
+/// Decode the domain a proof of the given game was generated for
+pub fn decode_domain<G: GameConfig>(proof: &SP1ProofWithPublicValues) -> crate::Result<Domain> {
+    let (domain, _, _) = open_envelope::<G>(proof.public_values.as_slice())?;
+    Ok(domain)
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
 pub enum ProvingMode {
     Plonk,
     Groth16,
+    Compressed,
     #[default]
     Core,
 }
 
-/// A generic prover for SP1 games
+impl From<ProvingMode> for SP1ProofMode {
+    fn from(value: ProvingMode) -> Self {
+        match value {
+            ProvingMode::Plonk => Self::Plonk,
+            ProvingMode::Groth16 => Self::Groth16,
+            ProvingMode::Compressed => Self::Compressed,
+            ProvingMode::Core => Self::Core,
+        }
+    }
+}
+
+impl std::fmt::Display for ProvingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProvingMode::Plonk => write!(f, "plonk"),
+            ProvingMode::Groth16 => write!(f, "groth16"),
+            ProvingMode::Compressed => write!(f, "compressed"),
+            ProvingMode::Core => write!(f, "core"),
+        }
+    }
+}
+
+/// Check that a proof of the given game can be verified inside the zkVM as a deferred proof,
+/// returning the encoded public values it commits to
+pub fn deferred_public_values<G: GameConfig>(
+    proof: &SP1ProofWithPublicValues,
+) -> crate::Result<Vec<u8>> {
+    if !matches!(proof.proof, SP1Proof::Compressed(_)) {
+        return Err(invalid_input(format!(
+            "expected a compressed {} proof",
+            G::NAME
+        )));
+    }
+
+    public_values_payload::<G>(proof.public_values.as_slice()).map_err(invalid_input)
+}
+
+/// Write compressed proofs to the stdin, so they can be verified inside the zkVM as deferred
+/// proofs against the given verifying key
+pub fn write_deferred_proofs(
+    stdin: &mut SP1Stdin,
+    vk: &SP1VerifyingKey,
+    proofs: Vec<SP1ProofWithPublicValues>,
+) -> crate::Result<()> {
+    for proof in proofs {
+        let SP1Proof::Compressed(proof) = proof.proof else {
+            return Err(Error::FailedToProveProvingGame(
+                "only compressed proofs can be verified inside the zkVM".to_string(),
+            ));
+        };
+        stdin.write_proof(*proof, vk.vk.clone());
+    }
+
+    Ok(())
+}
+
+/// A generic prover for SP1 games.
+///
+/// The wrapped prover can be any (shared) reference to an SP1 [`Prover`], i.e. an `EnvProver`
+/// picked through `SP1_PROVER`, or an explicitly configured cpu, cuda or mock prover.
 #[derive(Debug)]
 pub struct GameProver<P, G> {
     prover: P,
+    keys: OnceLock<(SP1ProvingKey, SP1VerifyingKey)>,
+    key_store: Option<KeyStore>,
+    domain: Domain,
     _game: std::marker::PhantomData<G>,
 }
 
 impl<P, G> GameProver<P, G>
 where
-    P: AsRef<EnvProver>,
+    P: Deref,
+    P::Target: Prover<CpuProverComponents>,
     G: GameConfig,
 {
     /// Create a new GameProver wrapping the given SP1 prover
     pub fn new(prover: P) -> Self {
         Self {
             prover,
+            keys: OnceLock::new(),
+            key_store: None,
+            domain: Domain::default(),
             _game: std::marker::PhantomData,
         }
     }
 
+    /// Generate proofs for the given domain instead of the default (zero) domain, and only
+    /// accept proof artifacts generated for it
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Get the domain proofs are generated for
+    pub fn domain(&self) -> &Domain {
+        &self.domain
+    }
+
+    /// Persist the proving and verifying keys in the given KeyStore, and load them from it
+    /// when they were generated for the same ELF
+    pub fn with_key_store(mut self, key_store: KeyStore) -> Self {
+        self.key_store = Some(key_store);
+        self
+    }
+
+    /// Get the proving and verifying keys, running setup on first use
+    fn keys(&self) -> &(SP1ProvingKey, SP1VerifyingKey) {
+        self.keys.get_or_init(|| {
+            let Some(key_store) = &self.key_store else {
+                return self.prover.setup(G::elf());
+            };
+
+            match key_store.load::<G>() {
+                Ok(Some(keys)) => return keys,
+                Ok(None) => tracing::info!("No stored keys for {}, running setup", G::NAME),
+                Err(e) => tracing::warn!("Failed to load stored keys for {}: {e}", G::NAME),
+            }
+
+            let (pk, vk) = self.prover.setup(G::elf());
+            if let Err(e) = key_store.save::<G>(&pk, &vk) {
+                tracing::warn!("Failed to store keys for {}: {e}", G::NAME);
+            }
+
+            (pk, vk)
+        })
+    }
+
+    /// Get the proving key for this game
+    pub fn proving_key(&self) -> &SP1ProvingKey {
+        &self.keys().0
+    }
+
+    /// Get the verifying key for this game
+    pub fn verifying_key(&self) -> &SP1VerifyingKey {
+        &self.keys().1
+    }
+
     /// Prove using raw input bytes
     pub fn prove(
         &self,
         input: &[u8],
         mode: ProvingMode,
     ) -> crate::Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
-        let mut stdin = SP1Stdin::new();
-        stdin.write_slice(input);
+        let decoded = decode_input::<G>(input)?;
+        self.prove_stdin(G::stdin(input, decoded)?, mode)
+    }
+
+    /// Prove using an already built zkVM stdin, to which the domain is appended
+    pub(crate) fn prove_stdin(
+        &self,
+        mut stdin: SP1Stdin,
+        mode: ProvingMode,
+    ) -> crate::Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        stdin.write(&self.domain);
 
-        // Setup the program for proving
-        let (pk, vk) = self.prover.as_ref().setup(G::elf());
+        // Setup the program for proving, reusing the keys across proofs
+        let (pk, vk) = self.keys();
 
         // Generate the proof
-        let proof = {
-            let prover = self.prover.as_ref().prove(&pk, &stdin);
-            let configured_prover = match mode {
-                ProvingMode::Core => prover,
-                ProvingMode::Groth16 => prover.groth16(),
-                ProvingMode::Plonk => prover.plonk(),
-            };
-            configured_prover
-                .run()
-                .map_err(|e| Error::FailedToProveProvingGame(e.to_string()))?
-        };
+        let proof = self
+            .prover
+            .prove(pk, &stdin, mode.into())
+            .map_err(|e| Error::FailedToProveProvingGame(e.to_string()))?;
 
         // Return the proof and verification key
-        Ok((proof, vk))
+        Ok((proof, vk.clone()))
+    }
+
+    /// Prove a typed input, serialized in the format consumed by the guest
+    pub fn prove_input(
+        &self,
+        input: &G::Input,
+        mode: ProvingMode,
+    ) -> crate::Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        self.prove(&encode_input::<G>(input), mode)
     }
 
     /// Prove a fixture
@@ -93,6 +446,88 @@ where
         self.prove(&raw_input, mode)
     }
 
+    /// Wrap a compressed proof into a Groth16 or Plonk proof, without executing the game again.
+    /// The proof is verified against the verifying key of this game before being wrapped.
+    pub fn wrap(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        mode: ProvingMode,
+    ) -> crate::Result<SP1ProofWithPublicValues> {
+        self.wrap_cancellable(proof, mode, &AtomicBool::new(false))
+    }
+
+    /// Wrap a compressed proof, stopping between the wrapping steps once `cancelled` is set
+    pub(crate) fn wrap_cancellable(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        mode: ProvingMode,
+        cancelled: &AtomicBool,
+    ) -> crate::Result<SP1ProofWithPublicValues> {
+        let checkpoint = || {
+            if cancelled.load(Ordering::Relaxed) {
+                return Err(Error::Cancelled);
+            }
+            Ok(())
+        };
+
+        // Reject anything that can't be wrapped before spending minutes on the wrapping steps
+        if !matches!(mode, ProvingMode::Groth16 | ProvingMode::Plonk) {
+            return Err(Error::FailedToWrapProof(format!(
+                "cannot wrap into {mode} proofs, expected groth16 or plonk"
+            )));
+        }
+        let SP1Proof::Compressed(compressed_proof) = &proof.proof else {
+            return Err(Error::FailedToWrapProof(
+                "only compressed proofs can be wrapped".to_string(),
+            ));
+        };
+        self.verify(proof, self.verifying_key())
+            .map_err(|e| Error::FailedToWrapProof(format!("not a valid {} proof: {e}", G::NAME)))?;
+        checkpoint()?;
+
+        let prover = self.prover.inner();
+        let shrunk_proof = prover
+            .shrink(*compressed_proof.clone(), Default::default())
+            .map_err(|e| Error::FailedToWrapProof(e.to_string()))?;
+        checkpoint()?;
+        let outer_proof = prover
+            .wrap_bn254(shrunk_proof, Default::default())
+            .map_err(|e| Error::FailedToWrapProof(e.to_string()))?;
+        checkpoint()?;
+
+        let wrapped_proof = match mode {
+            ProvingMode::Groth16 => {
+                let artifacts = if sp1_prover::build::sp1_dev_mode() {
+                    sp1_prover::build::try_build_groth16_bn254_artifacts_dev(
+                        &outer_proof.vk,
+                        &outer_proof.proof,
+                    )
+                } else {
+                    sp1_sdk::install::try_install_circuit_artifacts("groth16")
+                };
+                SP1Proof::Groth16(prover.wrap_groth16_bn254(outer_proof, &artifacts))
+            }
+            ProvingMode::Plonk => {
+                let artifacts = if sp1_prover::build::sp1_dev_mode() {
+                    sp1_prover::build::try_build_plonk_bn254_artifacts_dev(
+                        &outer_proof.vk,
+                        &outer_proof.proof,
+                    )
+                } else {
+                    sp1_sdk::install::try_install_circuit_artifacts("plonk")
+                };
+                SP1Proof::Plonk(prover.wrap_plonk_bn254(outer_proof, &artifacts))
+            }
+            ProvingMode::Compressed | ProvingMode::Core => unreachable!("checked above"),
+        };
+
+        // The public values are unchanged, only the proof itself is replaced
+        let mut proof = proof.clone();
+        proof.proof = wrapped_proof;
+
+        Ok(proof)
+    }
+
     /// Verify a proof against its verification key
     pub fn verify(
         &self,
@@ -100,11 +535,54 @@ where
         vk: &SP1VerifyingKey,
     ) -> crate::Result<()> {
         self.prover
-            .as_ref()
             .verify(proof, vk)
             .map_err(|e| Error::FailedToVerifyProof(e.to_string()))
     }
 
+    /// Verify a proof artifact against the verifying key of this game
+    pub fn verify_artifact(&self, artifact: &ProofArtifact) -> crate::Result<()> {
+        if artifact.game != G::NAME {
+            return Err(Error::FailedToVerifyProof(format!(
+                "proof is for game `{}`, expected `{}`",
+                artifact.game,
+                G::NAME
+            )));
+        }
+
+        let vk = self.verifying_key();
+        if artifact.vk_hash != vk.bytes32() {
+            return Err(Error::FailedToVerifyProof(format!(
+                "proof was generated for verifying key `{}`, expected `{}`",
+                artifact.vk_hash,
+                vk.bytes32()
+            )));
+        }
+
+        self.check_domain(&artifact.proof)?;
+        self.verify(&artifact.proof, vk)
+    }
+
+    /// Check that a proof was generated for the domain of this prover
+    pub fn check_domain(&self, proof: &SP1ProofWithPublicValues) -> crate::Result<()> {
+        let domain = decode_domain::<G>(proof)?;
+        if domain != self.domain {
+            return Err(Error::FailedToVerifyProof(format!(
+                "proof was generated for domain `{}`, expected `{}`",
+                hex::encode(domain),
+                hex::encode(self.domain)
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Load a saved proof artifact and verify it against the verifying key of this game
+    pub fn verify_saved_proof(&self, path: impl AsRef<Path>) -> crate::Result<ProofArtifact> {
+        let artifact = ProofArtifact::load(path)?;
+        self.verify_artifact(&artifact)?;
+        Ok(artifact)
+    }
+
     /// Write the solidity contract fixture to a file
     pub fn create_solidity_fixture(
         &self,
@@ -112,63 +590,195 @@ where
         vk: &SP1VerifyingKey,
         path: &str,
     ) -> crate::Result<()> {
-        let fixture = G::get_solidity_context(proof, vk);
+        let fixture = G::get_solidity_context(proof, vk)?;
         let fixture_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path);
         std::fs::create_dir_all(&fixture_path)
             .map_err(|e| Error::FailedToCreateSolidityFixture(anyhow::anyhow!(e)))?;
+        let json = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| Error::FailedToCreateSolidityFixture(anyhow::anyhow!(e)))?;
         std::fs::write(
             fixture_path.join(format!("{}-fixture.json", G::NAME).to_lowercase()),
-            serde_json::to_string_pretty(&fixture).unwrap(),
+            json,
         )
-        .expect("failed to write fixture");
-
-        Ok(())
+        .map_err(|e| Error::FailedToCreateSolidityFixture(anyhow::anyhow!(e)))
     }
 }
 
-/// A generic executor for SP1 games
+/// A generic executor for SP1 games, wrapping any (shared) reference to an SP1 [`Prover`]
 #[derive(Debug)]
 pub struct GameExecutor<E, G> {
     executor: E,
+    domain: Domain,
     _game: std::marker::PhantomData<G>,
 }
 
 impl<E, G> GameExecutor<E, G>
 where
-    E: AsRef<EnvProver>,
+    E: Deref,
+    E::Target: Prover<CpuProverComponents>,
     G: GameConfig,
 {
     /// Create a new GameExecutor wrapping the given SP1 executor
     pub fn new(executor: E) -> Self {
         Self {
             executor,
+            domain: Domain::default(),
             _game: std::marker::PhantomData,
         }
     }
 
-    /// Execute with raw input bytes
-    pub fn execute(&self, input: &[u8]) -> crate::Result<ExecutionReport> {
-        let mut stdin = SP1Stdin::new();
-        stdin.write_slice(input);
+    /// Execute for the given domain instead of the default (zero) domain
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Get the domain the game is executed for
+    pub fn domain(&self) -> &Domain {
+        &self.domain
+    }
+
+    /// Execute with raw input bytes, returning the committed public values and the report
+    pub fn execute(&self, input: &[u8]) -> crate::Result<(G::PublicValues, ExecutionReport)> {
+        let decoded = decode_input::<G>(input)?;
+        let (public_values, report) = self.execute_stdin(G::stdin(input, decoded)?)?;
+
+        // Decode the values the proof would commit to
+        let payload = public_values_payload::<G>(public_values.as_slice())?;
+        let public_values = G::decode_public_values(&payload)?;
+
+        Ok((public_values, report))
+    }
 
-        // Execute the program
-        let (_, report) = self
-            .executor
-            .as_ref()
+    /// Execute using an already built zkVM stdin, to which the domain is appended, returning the
+    /// raw committed public values
+    pub(crate) fn execute_stdin(
+        &self,
+        mut stdin: SP1Stdin,
+    ) -> crate::Result<(SP1PublicValues, ExecutionReport)> {
+        stdin.write(&self.domain);
+
+        self.executor
             .execute(G::elf(), &stdin)
-            .run()
-            .map_err(|e| Error::FailedToExecuteProvingGame(e.to_string()))?;
+            .map_err(|e| Error::FailedToExecuteProvingGame(e.to_string()))
+    }
 
-        Ok(report)
+    /// Execute a typed input, serialized in the format consumed by the guest
+    pub fn execute_input(
+        &self,
+        input: &G::Input,
+    ) -> crate::Result<(G::PublicValues, ExecutionReport)> {
+        self.execute(&encode_input::<G>(input))
+    }
+
+    /// Execute the game and check that it commits to the claimed public values, returning a
+    /// fault listing the mismatching fields if it doesn't
+    pub fn check_claim(&self, input: &[u8], claimed: &G::PublicValues) -> crate::Result<()> {
+        let (actual, _) = self.execute(input)?;
+        check_public_values::<G>(claimed, &actual)
     }
 
     /// Execute a fixture
-    pub fn execute_fixture(&self, fixture: G::Fixture) -> crate::Result<ExecutionReport> {
+    pub fn execute_fixture(
+        &self,
+        fixture: G::Fixture,
+    ) -> crate::Result<(G::PublicValues, ExecutionReport)> {
         let raw_input = G::get_fixture_input(&fixture);
         self.execute(&raw_input)
     }
 }
 
+/// A proof along with the metadata needed to verify or submit it on another machine
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProofArtifact {
+    /// The proof and its public values
+    pub proof: SP1ProofWithPublicValues,
+    /// The hash of the verifying key the proof was generated for
+    pub vk_hash: String,
+    /// The name of the game that was proven
+    pub game: String,
+    /// The mode the proof was generated with
+    pub mode: ProvingMode,
+    /// The hex encoded SHA-256 hash of the game's ELF
+    pub elf_hash: String,
+    /// Unix timestamp (in seconds) of when the artifact was created
+    pub timestamp: u64,
+}
+
+impl ProofArtifact {
+    /// Create a new ProofArtifact for a proof of the given game
+    pub fn new<G: GameConfig>(
+        proof: SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        mode: ProvingMode,
+    ) -> Self {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            proof,
+            vk_hash: vk.bytes32(),
+            game: G::NAME.to_string(),
+            mode,
+            elf_hash: hex::encode(G::elf_hash()),
+            timestamp,
+        }
+    }
+
+    /// Save the artifact to the given path, creating its parent directories if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| Error::FailedToSaveProofArtifact(anyhow::anyhow!(e)))?;
+        }
+
+        let bytes = bincode::serialize(self)
+            .map_err(|e| Error::FailedToSaveProofArtifact(anyhow::anyhow!(e)))?;
+        std::fs::write(path, bytes)
+            .map_err(|e| Error::FailedToSaveProofArtifact(anyhow::anyhow!(e)))
+    }
+
+    /// Load an artifact from the given path
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| Error::FailedToLoadProofArtifact(anyhow::anyhow!(e)))?;
+        bincode::deserialize(&bytes)
+            .map_err(|e| Error::FailedToLoadProofArtifact(anyhow::anyhow!(e)))
+    }
+}
+
+/// Convenience functions for working with the default prover and executor of any game
+pub mod defaults {
+    use super::*;
+    use crate::async_game::{AsyncGameExecutor, AsyncGameProver};
+    use sp1_sdk::EnvProver;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    /// Get a GameProver with the default SP1 prover
+    pub fn game_prover<G: GameConfig>() -> GameProver<Rc<EnvProver>, G> {
+        GameProver::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+    }
+
+    /// Get a GameExecutor with the default SP1 executor
+    pub fn game_executor<G: GameConfig>() -> GameExecutor<Rc<EnvProver>, G> {
+        GameExecutor::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+    }
+
+    /// Get an AsyncGameProver with the default SP1 prover
+    pub fn async_game_prover<G: GameConfig>() -> AsyncGameProver<Arc<EnvProver>, G> {
+        AsyncGameProver::new(Arc::new(sp1_sdk::ProverClient::from_env()))
+    }
+
+    /// Get an AsyncGameExecutor with the default SP1 executor
+    pub fn async_game_executor<G: GameConfig>() -> AsyncGameExecutor<Arc<EnvProver>, G> {
+        AsyncGameExecutor::new(Arc::new(sp1_sdk::ProverClient::from_env()))
+    }
+}
+
 /// Helper function to create CSV writer for reports
 #[cfg(test)]
 pub fn create_csv_writer(
@@ -181,3 +791,150 @@ pub fn create_csv_writer(
         .from_path(file_path)
         .expect("Couldn't create CSV writer")
 }
+
+/// Helper function to read the proving mode for reports, defaults to core proofs
+#[cfg(test)]
+pub fn proving_mode_from_env(proving_mode_env_var: &str) -> ProvingMode {
+    std::env::var(proving_mode_env_var)
+        .map(|mode| {
+            <ProvingMode as clap::ValueEnum>::from_str(&mode, true).expect("Invalid proving mode")
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::BlockExecutionGame;
+    use fuel_zkvm_primitives_test_fixtures::block_execution_fixtures::fixtures::all_fixtures;
+    use std::rc::Rc;
+
+    #[test]
+    fn proof_artifact_save_and_load_round_trip() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+
+        // A mock proof is enough to exercise the serialization
+        let prover = GameProver::<_, BlockExecutionGame>::new(Rc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ));
+        let (proof, vk) = prover.prove_fixture(fixture, ProvingMode::Core).unwrap();
+
+        let artifact = ProofArtifact::new::<BlockExecutionGame>(proof, &vk, ProvingMode::Core);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("proof.bin");
+        artifact.save(&path).unwrap();
+
+        let loaded = ProofArtifact::load(&path).unwrap();
+        assert_eq!(loaded.game, BlockExecutionGame::NAME);
+        assert_eq!(loaded.mode, ProvingMode::Core);
+        assert_eq!(loaded.vk_hash, vk.bytes32());
+        assert_eq!(loaded.elf_hash, artifact.elf_hash);
+        assert_eq!(loaded.timestamp, artifact.timestamp);
+        assert_eq!(
+            loaded.proof.public_values.as_slice(),
+            artifact.proof.public_values.as_slice()
+        );
+    }
+
+    #[test]
+    fn mock_prover_proves_and_verifies_artifacts() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let prover = GameProver::<_, BlockExecutionGame>::new(Rc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ));
+
+        let (proof, vk) = prover.prove_fixture(fixture, ProvingMode::Core).unwrap();
+        let artifact = ProofArtifact::new::<BlockExecutionGame>(proof, &vk, ProvingMode::Core);
+        prover.verify_artifact(&artifact).unwrap();
+
+        let mut foreign = artifact.clone();
+        foreign.game = "decompression".to_string();
+        assert!(matches!(
+            prover.verify_artifact(&foreign),
+            Err(Error::FailedToVerifyProof(_))
+        ));
+
+        // Proofs of another network are rejected
+        let mainnet_prover = GameProver::<_, BlockExecutionGame>::new(Rc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ))
+        .with_domain(chain_domain(9889));
+        assert!(matches!(
+            mainnet_prover.verify_artifact(&artifact),
+            Err(Error::FailedToVerifyProof(_))
+        ));
+    }
+
+    #[test]
+    fn wrap_rejects_other_targets_and_proofs() {
+        let fixture = all_fixtures().into_iter().next().unwrap();
+        let prover = GameProver::<_, BlockExecutionGame>::new(Rc::new(
+            sp1_sdk::ProverClient::builder().mock().build(),
+        ));
+        let (proof, _) = prover.prove_fixture(fixture, ProvingMode::Core).unwrap();
+
+        // Only Groth16 and Plonk proofs can be wrapped into
+        for mode in [ProvingMode::Core, ProvingMode::Compressed] {
+            assert!(matches!(
+                prover.wrap(&proof, mode),
+                Err(Error::FailedToWrapProof(e)) if e.contains("expected groth16 or plonk")
+            ));
+        }
+
+        // Only compressed proofs can be wrapped
+        assert!(matches!(
+            prover.wrap(&proof, ProvingMode::Groth16),
+            Err(Error::FailedToWrapProof(e)) if e.contains("only compressed proofs")
+        ));
+    }
+
+    /// Check that garbage bytes don't decode as an input of the given game
+    fn assert_rejects_malformed_input<G: GameConfig>() {
+        let result = decode_input::<G>(&[0u8; 3]);
+        assert!(
+            matches!(result, Err(Error::FailedToDeserializeInput(_))),
+            "{} accepted a malformed input",
+            G::NAME
+        );
+    }
+
+    #[test]
+    fn decode_input_rejects_malformed_input() {
+        assert_rejects_malformed_input::<crate::block_aggregation_game::BlockAggregationGame>();
+        assert_rejects_malformed_input::<crate::block_range_game::BlockRangeGame>();
+    }
+
+    #[test]
+    fn public_values_payload_checks_the_envelope() {
+        let payload = vec![1, 2, 3];
+        let envelope = |game_id, kind| {
+            PublicValuesEnvelope::seal(game_id, chain_domain(0), kind, payload.clone())
+        };
+
+        let bytes = envelope(game_id::BLOCK_EXECUTION, PayloadKind::PublicValues);
+        assert_eq!(
+            public_values_payload::<BlockExecutionGame>(&bytes).unwrap(),
+            payload
+        );
+
+        // Public values of another game are rejected
+        let bytes = envelope(game_id::DECOMPRESSION, PayloadKind::PublicValues);
+        assert!(matches!(
+            public_values_payload::<BlockExecutionGame>(&bytes),
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+
+        // Status payloads are not public values
+        let bytes = envelope(game_id::BLOCK_EXECUTION, PayloadKind::Status);
+        assert!(matches!(
+            public_values_payload::<BlockExecutionGame>(&bytes),
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+
+        // Raw public values without an envelope are rejected
+        assert!(matches!(
+            public_values_payload::<BlockExecutionGame>(&payload),
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+    }
+}
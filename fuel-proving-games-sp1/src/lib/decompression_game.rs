@@ -1,12 +1,20 @@
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const FUEL_SP1_ELF: &[u8] = sp1_sdk::include_elf!("fuel-decompression-game-sp1");
 
-use crate::common::{GameConfig, GameExecutor, GameProver};
-use crate::Result;
+use crate::async_game::{AsyncGameExecutor, AsyncGameProver};
+use crate::common::{
+    decode_public_values, encode_input, game_id, FieldMismatch, GameConfig, GameExecutor,
+    GameProver,
+};
+use crate::native::NativeGame;
+use crate::status::StatusGame;
+use crate::{Error, Result};
 use alloy_sol_types::SolType;
-use fuel_zkvm_primitives_prover::games::decompression_game::PublicValuesStruct;
+use fuel_zkvm_primitives_prover::games::decompression_game::{prove, Input, PublicValuesStruct};
 use fuel_zkvm_primitives_test_fixtures::decompression_fixtures::Fixture;
-use sp1_sdk::{EnvProver, ExecutionReport, HashableKey, SP1ProofWithPublicValues, SP1VerifyingKey};
+use sp1_sdk::{
+    EnvProver, ExecutionReport, HashableKey, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey,
+};
 
 /// Configuration for the Decompression Game
 #[derive(Debug, Clone)]
@@ -23,13 +31,28 @@ pub struct SolidityContext {
     proof: String,
 }
 
+/// The public values committed by the Decompression Game
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PublicValues {
+    /// The height of the first block recovered from the blobs
+    pub first_block_height: u64,
+    /// The height of the last block recovered from the blobs
+    pub last_block_height: u64,
+}
+
 impl GameConfig for DecompressionGame {
     const NAME: &'static str = "decompression";
 
+    const GAME_ID: u32 = game_id::DECOMPRESSION;
+
     type Fixture = Fixture;
 
     type SolidityContext = SolidityContext;
 
+    type PublicValues = PublicValues;
+
+    type Input = Input;
+
     fn elf() -> &'static [u8] {
         FUEL_SP1_ELF
     }
@@ -38,27 +61,101 @@ impl GameConfig for DecompressionGame {
         Fixture::get_input_for_fixture(fixture)
     }
 
+    fn describe_input(input: &Self::Input) -> Vec<(String, String)> {
+        // The heights covered by the blobs are only known once they are decompressed
+        let heights = Self::run_native(&encode_input::<Self>(input))
+            .and_then(|public_values| Self::decode_public_values(&public_values))
+            .map(|heights| {
+                format!(
+                    "{}..={}",
+                    heights.first_block_height, heights.last_block_height
+                )
+            })
+            .unwrap_or_else(|e| format!("unavailable: {e}"));
+
+        vec![
+            ("blobs".to_string(), input.blobs.len().to_string()),
+            ("heights".to_string(), heights),
+        ]
+    }
+
+    fn stdin(bytes: &[u8], _input: Self::Input) -> Result<SP1Stdin> {
+        Ok(Self::status_stdin(bytes, false))
+    }
+
+    fn decode_public_values(bytes: &[u8]) -> Result<Self::PublicValues> {
+        let PublicValuesStruct {
+            first_block_height,
+            last_block_height,
+        } = PublicValuesStruct::abi_decode(bytes, false)
+            .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?;
+
+        Ok(PublicValues {
+            first_block_height: u64::try_from(first_block_height)
+                .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?,
+            last_block_height: u64::try_from(last_block_height)
+                .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?,
+        })
+    }
+
+    fn diff_public_values(
+        claimed: &Self::PublicValues,
+        actual: &Self::PublicValues,
+    ) -> Vec<FieldMismatch> {
+        [
+            FieldMismatch::compare(
+                "first_block_height",
+                &claimed.first_block_height,
+                &actual.first_block_height,
+            ),
+            FieldMismatch::compare(
+                "last_block_height",
+                &claimed.last_block_height,
+                &actual.last_block_height,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
     fn get_solidity_context(
         proof: &SP1ProofWithPublicValues,
         vk: &SP1VerifyingKey,
-    ) -> Self::SolidityContext {
+    ) -> Result<Self::SolidityContext> {
         let bytes = proof.public_values.as_slice();
-
-        let PublicValuesStruct {
+        let PublicValues {
             first_block_height,
             last_block_height,
-        } = PublicValuesStruct::abi_decode(bytes, false).unwrap();
+        } = decode_public_values::<Self>(proof)?;
 
         // Create the testing ctx so we can test things end-to-end.
         let ctx = SolidityContext {
-            first_block_height: first_block_height.to_be_bytes(),
-            last_block_height: last_block_height.to_be_bytes(),
+            first_block_height: uint256_bytes(first_block_height),
+            last_block_height: uint256_bytes(last_block_height),
             vkey: vk.bytes32().to_string(),
             public_values: format!("0x{}", hex::encode(bytes)),
             proof: format!("0x{}", hex::encode(proof.bytes())),
         };
 
-        ctx
+        Ok(ctx)
+    }
+}
+
+/// Encode a height as the big-endian bytes of the `uint256` committed by the guest
+fn uint256_bytes(value: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+impl StatusGame for DecompressionGame {}
+
+impl NativeGame for DecompressionGame {
+    fn run_native(input: &[u8]) -> Result<Vec<u8>> {
+        prove(input)
+            .map(|public_values| PublicValuesStruct::abi_encode(&public_values))
+            .map_err(|e| Error::FailedToExecuteProvingGame(format!("{e:?}")))
     }
 }
 
@@ -68,19 +165,37 @@ pub type DecompressionProver<P> = GameProver<P, DecompressionGame>;
 /// Type alias for Decompression Game Executor
 pub type DecompressionExecutor<E> = GameExecutor<E, DecompressionGame>;
 
+/// Type alias for the async Decompression Game Prover
+pub type AsyncDecompressionProver<P> = AsyncGameProver<P, DecompressionGame>;
+
+/// Type alias for the async Decompression Game Executor
+pub type AsyncDecompressionExecutor<E> = AsyncGameExecutor<E, DecompressionGame>;
+
 /// Convenience functions for working with the default prover and executor
 pub mod defaults {
     use super::*;
+    use crate::common;
     use std::rc::Rc;
+    use std::sync::Arc;
 
     /// Get a DecompressionProver with the default SP1 prover
     pub fn game_prover() -> DecompressionProver<Rc<EnvProver>> {
-        DecompressionProver::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+        common::defaults::game_prover()
     }
 
     /// Get a DecompressionExecutor with the default SP1 executor
     pub fn game_executor() -> DecompressionExecutor<Rc<EnvProver>> {
-        DecompressionExecutor::new(Rc::new(sp1_sdk::ProverClient::from_env()))
+        common::defaults::game_executor()
+    }
+
+    /// Get an AsyncDecompressionProver with the default SP1 prover
+    pub fn async_game_prover() -> AsyncDecompressionProver<Arc<EnvProver>> {
+        common::defaults::async_game_prover()
+    }
+
+    /// Get an AsyncDecompressionExecutor with the default SP1 executor
+    pub fn async_game_executor() -> AsyncDecompressionExecutor<Arc<EnvProver>> {
+        common::defaults::async_game_executor()
     }
 
     /// Prove a fixture with the default prover
@@ -89,7 +204,7 @@ pub mod defaults {
     }
 
     /// Execute a fixture with the default executor
-    pub fn execute_fixture(fixture: Fixture) -> Result<ExecutionReport> {
+    pub fn execute_fixture(fixture: Fixture) -> Result<(PublicValues, ExecutionReport)> {
         game_executor().execute_fixture(fixture)
     }
 }
@@ -97,7 +212,7 @@ pub mod defaults {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::create_csv_writer;
+    use crate::common::{create_csv_writer, decode_input, proving_mode_from_env, ProvingMode};
     use fuel_zkvm_primitives_test_fixtures::decompression_fixtures::all_fixtures;
     use serde::Serialize;
 
@@ -112,10 +227,109 @@ mod tests {
     #[derive(Serialize)]
     struct ProvingReport {
         fixture: Fixture,
+        mode: ProvingMode,
         proving_time: u128,
         verification_time: u128,
     }
 
+    #[test]
+    fn decode_public_values_rejects_malformed_bytes() {
+        let result = DecompressionGame::decode_public_values(&[0u8; 3]);
+        assert!(matches!(
+            result,
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+    }
+
+    #[test]
+    fn uint256_bytes_matches_the_committed_heights() {
+        let public_values = PublicValuesStruct {
+            first_block_height: 14133451u64.into(),
+            last_block_height: u64::MAX.into(),
+        };
+
+        assert_eq!(
+            uint256_bytes(14133451),
+            public_values.first_block_height.to_be_bytes()
+        );
+        assert_eq!(
+            uint256_bytes(u64::MAX),
+            public_values.last_block_height.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn diff_public_values_reports_mismatching_heights() {
+        let claimed = PublicValues {
+            first_block_height: 10,
+            last_block_height: 20,
+        };
+        let actual = PublicValues {
+            first_block_height: 10,
+            last_block_height: 21,
+        };
+
+        assert!(DecompressionGame::diff_public_values(&claimed, &claimed).is_empty());
+
+        let mismatches = DecompressionGame::diff_public_values(&claimed, &actual);
+        assert_eq!(
+            mismatches,
+            vec![FieldMismatch {
+                field: "last_block_height",
+                claimed: "20".to_string(),
+                actual: "21".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn native_execution_matches_zkvm_execution() {
+        let executor = defaults::game_executor();
+        let native_executor = crate::native::NativeExecutor::<DecompressionGame>::new();
+
+        for fixture in all_fixtures() {
+            let (public_values, _) = executor.execute_fixture(fixture.clone()).unwrap();
+            assert_eq!(
+                native_executor.execute_fixture(fixture).unwrap(),
+                public_values
+            );
+        }
+    }
+
+    #[test]
+    fn describe_input_reports_the_covered_heights() {
+        let native_executor = crate::native::NativeExecutor::<DecompressionGame>::new();
+
+        for fixture in all_fixtures() {
+            let raw_input = DecompressionGame::get_fixture_input(&fixture);
+            let input = decode_input::<DecompressionGame>(&raw_input).unwrap();
+            let public_values = native_executor.execute(&raw_input).unwrap();
+
+            assert_eq!(
+                DecompressionGame::describe_input(&input),
+                vec![
+                    ("blobs".to_string(), input.blobs.len().to_string()),
+                    (
+                        "heights".to_string(),
+                        format!(
+                            "{}..={}",
+                            public_values.first_block_height, public_values.last_block_height
+                        )
+                    ),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn typed_inputs_encode_to_the_raw_fixture_inputs() {
+        for fixture in all_fixtures() {
+            let raw_input = DecompressionGame::get_fixture_input(&fixture);
+            let input = decode_input::<DecompressionGame>(&raw_input).unwrap();
+            assert_eq!(encode_input::<DecompressionGame>(&input), raw_input);
+        }
+    }
+
     #[test]
     fn run_all_fixtures_and_collect_report() {
         let fixtures = all_fixtures();
@@ -126,7 +340,7 @@ mod tests {
 
         for fixture in fixtures {
             // Execute the fixture
-            let report = executor.execute_fixture(fixture.clone()).unwrap();
+            let (_, report) = executor.execute_fixture(fixture.clone()).unwrap();
 
             let perf_report = ExecutionReport {
                 fixture: fixture.clone(),
@@ -149,13 +363,12 @@ mod tests {
 
         // Create a reusable prover
         let prover = defaults::game_prover();
+        let mode = proving_mode_from_env("FUEL_SP1_PROVING_MODE");
 
         for fixture in fixtures {
             // Prove the fixture
             let start_time = std::time::Instant::now();
-            let (proof, vk) = prover
-                .prove_fixture(fixture.clone(), Default::default())
-                .unwrap();
+            let (proof, vk) = prover.prove_fixture(fixture.clone(), mode).unwrap();
             let proving_time = start_time.elapsed().as_millis();
 
             let start_time = std::time::Instant::now();
@@ -164,6 +377,7 @@ mod tests {
 
             let perf_report = ProvingReport {
                 fixture: fixture.clone(),
+                mode,
                 proving_time,
                 verification_time,
             };
@@ -0,0 +1,172 @@
+//! Decoding and summarizing game inputs, to see what is inside an input without proving it
+
+use crate::common::GameConfig;
+use crate::Result;
+use fuel_game_envelope_sp1::input_hash;
+use serde_json::Value;
+
+/// The maximum depth of the outline of an input
+const OUTLINE_DEPTH: usize = 4;
+
+/// The maximum length of a value in the outline of an input
+const OUTLINE_VALUE_LEN: usize = 66;
+
+/// A human readable summary of a game input
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct InputSummary {
+    /// The name of the game the input is for
+    pub game: &'static str,
+    /// The size of the raw input in bytes
+    pub size: usize,
+    /// The hex encoded SHA-256 hash of the raw input
+    pub input_hash: String,
+    /// Why the input would be rejected before running the zkVM, if it would be
+    pub rejected: Option<String>,
+    /// The game specific details of the input, as `(name, value)` pairs. They serialize as an
+    /// object, in order
+    #[serde(serialize_with = "serialize_details")]
+    pub details: Vec<(String, String)>,
+}
+
+fn serialize_details<S: serde::Serializer>(
+    details: &[(String, String)],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_map(details.iter().map(|(name, value)| (name, value)))
+}
+
+impl std::fmt::Display for InputSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "game: {}", self.game)?;
+        writeln!(f, "size: {} bytes", self.size)?;
+        writeln!(f, "input hash: 0x{}", self.input_hash)?;
+        match &self.rejected {
+            Some(reason) => writeln!(f, "rejected: {reason}")?,
+            None => writeln!(f, "rejected: no")?,
+        }
+        for (name, value) in &self.details {
+            writeln!(f, "{name}: {value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decode raw input bytes of the given game and summarize them.
+///
+/// Inputs that decode but fail the sanity checks of the game are still summarized, along with
+/// the reason they would be rejected.
+pub fn inspect_input<G: GameConfig>(bytes: &[u8]) -> Result<InputSummary> {
+    let input: G::Input = bincode::deserialize(bytes)?;
+
+    Ok(InputSummary {
+        game: G::NAME,
+        size: bytes.len(),
+        input_hash: hex::encode(input_hash(bytes)),
+        rejected: crate::common::decode_input::<G>(bytes)
+            .err()
+            .map(|e| e.to_string()),
+        details: G::describe_input(&input),
+    })
+}
+
+/// Outline the structure of a serializable value, listing its scalar fields and the length of
+/// its sequences, down to a fixed depth
+pub fn outline<T: serde::Serialize>(value: &T) -> Vec<(String, String)> {
+    let mut lines = vec![];
+    match serde_json::to_value(value) {
+        Ok(value) => outline_value("", &value, 0, &mut lines),
+        Err(e) => lines.push(("outline".to_string(), format!("unavailable: {e}"))),
+    }
+    lines
+}
+
+fn outline_value(path: &str, value: &Value, depth: usize, lines: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(fields) if depth < OUTLINE_DEPTH => {
+            for (name, field) in fields {
+                let path = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{path}.{name}")
+                };
+                outline_value(&path, field, depth + 1, lines);
+            }
+        }
+        Value::Object(fields) => lines.push((path.to_string(), format!("{} fields", fields.len()))),
+        Value::Array(items) => lines.push((path.to_string(), format!("{} items", items.len()))),
+        value => {
+            let mut value = value.to_string();
+            if value.len() > OUTLINE_VALUE_LEN {
+                value.truncate(OUTLINE_VALUE_LEN);
+                value.push_str("...");
+            }
+            lines.push((path.to_string(), value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Header {
+        height: u32,
+        prev_root: [u8; 2],
+    }
+
+    #[derive(serde::Serialize)]
+    struct Block {
+        header: Header,
+        transactions: Vec<u8>,
+    }
+
+    #[test]
+    fn outline_lists_scalars_and_sequence_lengths() {
+        let block = Block {
+            header: Header {
+                height: 42,
+                prev_root: [1, 2],
+            },
+            transactions: vec![1, 2, 3],
+        };
+
+        assert_eq!(
+            outline(&block),
+            vec![
+                ("header.height".to_string(), "42".to_string()),
+                ("header.prev_root".to_string(), "2 items".to_string()),
+                ("transactions".to_string(), "3 items".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn details_serialize_as_an_object() {
+        let summary = InputSummary {
+            game: "block_execution",
+            size: 3,
+            input_hash: "00".to_string(),
+            rejected: None,
+            details: vec![
+                ("height".to_string(), "42".to_string()),
+                ("transactions".to_string(), "3 items".to_string()),
+            ],
+        };
+
+        assert_eq!(
+            serde_json::to_value(&summary).unwrap()["details"],
+            serde_json::json!({ "height": "42", "transactions": "3 items" })
+        );
+    }
+
+    #[test]
+    fn inspect_input_rejects_undecodable_input() {
+        let result = inspect_input::<crate::block_range_game::BlockRangeGame>(&[0u8; 3]);
+        assert!(matches!(
+            result,
+            Err(crate::Error::FailedToDeserializeInput(_))
+        ));
+    }
+}
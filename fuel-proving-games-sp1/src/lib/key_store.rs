@@ -0,0 +1,188 @@
+use crate::common::GameConfig;
+use crate::{Error, Result};
+use sp1_sdk::{SP1ProvingKey, SP1VerifyingKey};
+use std::path::{Path, PathBuf};
+
+/// Extension of the serialized proving key files
+const PROVING_KEY_EXTENSION: &str = "pk";
+
+/// Extension of the serialized verifying key files
+const VERIFYING_KEY_EXTENSION: &str = "vk";
+
+/// A directory backed store for the proving and verifying keys of SP1 games.
+///
+/// Keys are stored per game and keyed by the hash of the game's ELF, so that a
+/// rebuilt guest never picks up keys generated for a previous version of it.
+#[derive(Debug, Clone)]
+pub struct KeyStore {
+    dir: PathBuf,
+}
+
+impl KeyStore {
+    /// Create a new KeyStore rooted at the given directory
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory the keys are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Load the keys for the given game, if keys for its current ELF are stored
+    pub fn load<G: GameConfig>(&self) -> Result<Option<(SP1ProvingKey, SP1VerifyingKey)>> {
+        let pk_path = self.key_path::<G>(PROVING_KEY_EXTENSION);
+        let vk_path = self.key_path::<G>(VERIFYING_KEY_EXTENSION);
+
+        if !pk_path.exists() || !vk_path.exists() {
+            return Ok(None);
+        }
+
+        let pk = Self::read_key(&pk_path)?;
+        let vk = Self::read_key(&vk_path)?;
+
+        Ok(Some((pk, vk)))
+    }
+
+    /// Store the keys for the given game, keyed by its current ELF
+    pub fn save<G: GameConfig>(&self, pk: &SP1ProvingKey, vk: &SP1VerifyingKey) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| Error::FailedToAccessKeyStore(anyhow::anyhow!(e)))?;
+
+        Self::write_key(&self.key_path::<G>(PROVING_KEY_EXTENSION), pk)?;
+        Self::write_key(&self.key_path::<G>(VERIFYING_KEY_EXTENSION), vk)?;
+
+        Ok(())
+    }
+
+    fn key_path<G: GameConfig>(&self, extension: &str) -> PathBuf {
+        self.dir.join(format!(
+            "{}-{}.{}",
+            G::NAME,
+            hex::encode(G::elf_hash()),
+            extension
+        ))
+    }
+
+    fn read_key<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+        let bytes =
+            std::fs::read(path).map_err(|e| Error::FailedToAccessKeyStore(anyhow::anyhow!(e)))?;
+        bincode::deserialize(&bytes).map_err(|e| Error::FailedToAccessKeyStore(anyhow::anyhow!(e)))
+    }
+
+    fn write_key<T: serde::Serialize>(path: &Path, key: &T) -> Result<()> {
+        let bytes = bincode::serialize(key)
+            .map_err(|e| Error::FailedToAccessKeyStore(anyhow::anyhow!(e)))?;
+
+        // Write to a temporary file first, so that an interrupted write never leaves a
+        // truncated key behind. The temporary file is unique to the key and the process, so
+        // that processes sharing the store never rename each other's keys into place.
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let tmp_path = path.with_extension(format!("{extension}.{}.tmp", std::process::id()));
+        std::fs::write(&tmp_path, bytes)
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .map_err(|e| Error::FailedToAccessKeyStore(anyhow::anyhow!(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::BlockExecutionGame;
+    use crate::common::GameProver;
+    use crate::decompression_game::DecompressionGame;
+    use sp1_sdk::{HashableKey, Prover};
+    use std::rc::Rc;
+
+    /// Store keys as the keys of the block execution game built from an ELF with the given hash
+    fn save_block_execution_keys(
+        store: &KeyStore,
+        elf_hash: [u8; 32],
+        (pk, vk): &(SP1ProvingKey, SP1VerifyingKey),
+    ) {
+        let path = |extension: &str| {
+            store.dir().join(format!(
+                "{}-{}.{extension}",
+                BlockExecutionGame::NAME,
+                hex::encode(elf_hash)
+            ))
+        };
+        std::fs::create_dir_all(store.dir()).unwrap();
+        KeyStore::write_key(&path(PROVING_KEY_EXTENSION), pk).unwrap();
+        KeyStore::write_key(&path(VERIFYING_KEY_EXTENSION), vk).unwrap();
+    }
+
+    #[test]
+    fn game_prover_loads_stored_keys_instead_of_running_setup() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = KeyStore::new(dir.path());
+        let client = Rc::new(sp1_sdk::ProverClient::builder().mock().build());
+
+        // Keys that setup can't produce for the block execution game show that they were loaded
+        let keys = client.setup(DecompressionGame::elf());
+        save_block_execution_keys(&store, BlockExecutionGame::elf_hash(), &keys);
+
+        let prover = GameProver::<_, BlockExecutionGame>::new(client).with_key_store(store);
+        assert_eq!(prover.verifying_key().bytes32(), keys.1.bytes32());
+    }
+
+    #[test]
+    fn game_prover_ignores_keys_of_other_elfs() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = KeyStore::new(dir.path());
+        let client = Rc::new(sp1_sdk::ProverClient::builder().mock().build());
+
+        let keys = client.setup(DecompressionGame::elf());
+        save_block_execution_keys(&store, [0; 32], &keys);
+
+        let (_, vk) = client.setup(BlockExecutionGame::elf());
+        let prover = GameProver::<_, BlockExecutionGame>::new(client).with_key_store(store.clone());
+        assert_eq!(prover.verifying_key().bytes32(), vk.bytes32());
+
+        // The keys generated by setup are stored for the current ELF
+        let (_, stored_vk) = store.load::<BlockExecutionGame>().unwrap().unwrap();
+        assert_eq!(stored_vk.bytes32(), vk.bytes32());
+    }
+
+    #[test]
+    fn temporary_key_files_are_unique_per_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = KeyStore::new(dir.path());
+        let client = sp1_sdk::ProverClient::builder().mock().build();
+
+        let (pk, vk) = client.setup(BlockExecutionGame::elf());
+        store.save::<BlockExecutionGame>(&pk, &vk).unwrap();
+
+        let mut files = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        let name = format!(
+            "{}-{}",
+            BlockExecutionGame::NAME,
+            hex::encode(BlockExecutionGame::elf_hash())
+        );
+        assert_eq!(files, vec![format!("{name}.pk"), format!("{name}.vk")]);
+    }
+
+    #[test]
+    fn save_and_load_keys_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = KeyStore::new(dir.path());
+
+        assert!(store.load::<BlockExecutionGame>().unwrap().is_none());
+
+        // The keys don't depend on the prover, so an explicit mock prover keeps the test
+        // independent of `SP1_PROVER`
+        let prover = sp1_sdk::ProverClient::builder().mock().build();
+        let (pk, vk) = prover.setup(BlockExecutionGame::elf());
+        store.save::<BlockExecutionGame>(&pk, &vk).unwrap();
+
+        let (_, loaded_vk) = store.load::<BlockExecutionGame>().unwrap().unwrap();
+        assert_eq!(loaded_vk.bytes32(), vk.bytes32());
+    }
+}
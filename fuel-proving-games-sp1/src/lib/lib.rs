@@ -1,12 +1,23 @@
+pub mod async_game;
+pub mod block_aggregation_game;
 pub mod block_execution_game;
+pub mod block_range_game;
+pub mod challenge;
 pub mod common;
 pub mod decompression_game;
+pub mod inspect;
+pub mod key_store;
+pub mod native;
+pub mod status;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     /// This error occurs when the input cannot be deserialized using bincode
     #[error("failed to deserialize input: `{0}`")]
     FailedToDeserializeInput(#[from] bincode::Error),
+    /// This error occurs when an input decodes fine but fails the sanity checks of its game
+    #[error("invalid input: `{0}`")]
+    InvalidInput(String),
     /// This error occurs when the public outputs from the zkvm cannot be deserialized
     #[error("failed to deserialize public output: `{0}`")]
     FailedToDeserializePublicOutput(String),
@@ -18,11 +29,29 @@ pub enum Error {
     FailedToProveProvingGame(String),
     #[error("failed to verify proof: `{0}`")]
     FailedToVerifyProof(String),
+    /// This error occurs when a compressed proof cannot be wrapped into a Groth16 or Plonk proof
+    #[error("failed to wrap proof: `{0}`")]
+    FailedToWrapProof(String),
     /// This error occurs when a fault/mismatch is detected
     #[error("FAULT: `{0}`")]
     Fault(String),
     #[error("failed to create solidity fixture: `{0}`")]
     FailedToCreateSolidityFixture(anyhow::Error),
+    /// This error occurs when the keys cannot be read from or written to the key store
+    #[error("failed to access key store: `{0}`")]
+    FailedToAccessKeyStore(anyhow::Error),
+    /// This error occurs when a proof artifact cannot be written to disk
+    #[error("failed to save proof artifact: `{0}`")]
+    FailedToSaveProofArtifact(anyhow::Error),
+    /// This error occurs when a proof artifact cannot be read from disk
+    #[error("failed to load proof artifact: `{0}`")]
+    FailedToLoadProofArtifact(anyhow::Error),
+    /// This error occurs when an async task is cancelled before it completes
+    #[error("task was cancelled")]
+    Cancelled,
+    /// This error occurs when the worker thread running an async task fails
+    #[error("failed to join worker thread: `{0}`")]
+    FailedToJoinWorker(String),
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,105 @@
+//! Native execution, where the logic of a game runs directly on the host instead of inside the
+//! zkVM. It takes milliseconds instead of minutes and can be stepped through with a debugger, but
+//! proves nothing, so it's meant for debugging the game logic and triaging inputs.
+
+use crate::common::{check_public_values, decode_input, encode_input, GameConfig};
+use crate::status::{input_hash, GameStatus, StatusGame};
+use crate::{Error, Result};
+
+/// A game whose logic can run natively on the host, without the zkVM
+pub trait NativeGame: GameConfig {
+    /// Run the logic of the game on raw input bytes, returning the encoded public values its
+    /// guest would commit to
+    fn run_native(input: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// An executor running the logic of a game natively on the host
+#[derive(Debug, Clone)]
+pub struct NativeExecutor<G> {
+    _game: std::marker::PhantomData<G>,
+}
+
+impl<G: NativeGame> Default for NativeExecutor<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: NativeGame> NativeExecutor<G> {
+    /// Create a new NativeExecutor
+    pub fn new() -> Self {
+        Self {
+            _game: std::marker::PhantomData,
+        }
+    }
+
+    /// Execute with raw input bytes, returning the public values the game commits to
+    pub fn execute(&self, input: &[u8]) -> Result<G::PublicValues> {
+        decode_input::<G>(input)?;
+        G::decode_public_values(&G::run_native(input)?)
+    }
+
+    /// Execute a typed input, serialized in the format consumed by the guest
+    pub fn execute_input(&self, input: &G::Input) -> Result<G::PublicValues> {
+        self.execute(&encode_input::<G>(input))
+    }
+
+    /// Execute the game and check that it commits to the claimed public values, returning a
+    /// fault listing the mismatching fields if it doesn't
+    pub fn check_claim(&self, input: &[u8], claimed: &G::PublicValues) -> Result<()> {
+        let actual = self.execute(input)?;
+        check_public_values::<G>(claimed, &actual)
+    }
+
+    /// Execute a fixture
+    pub fn execute_fixture(&self, fixture: G::Fixture) -> Result<G::PublicValues> {
+        self.execute(&G::get_fixture_input(&fixture))
+    }
+}
+
+impl<G: NativeGame + StatusGame> NativeExecutor<G> {
+    /// Execute with raw input bytes in status mode, returning the status the guest would commit
+    /// to. As in the guest, any failure of the game is reported as an invalid input.
+    pub fn execute_status(&self, input: &[u8]) -> Result<GameStatus<G::PublicValues>> {
+        let input_hash = input_hash(input);
+        match G::run_native(input) {
+            Ok(public_values) => Ok(GameStatus::Ok {
+                input_hash,
+                public_values: G::decode_public_values(&public_values)?,
+            }),
+            Err(Error::FailedToExecuteProvingGame(_)) => {
+                Ok(GameStatus::InvalidInput { input_hash })
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::BlockExecutionGame;
+
+    #[test]
+    fn execute_status_reports_invalid_input() {
+        let executor = NativeExecutor::<BlockExecutionGame>::new();
+
+        let status = executor.execute_status(&[0u8; 3]).unwrap();
+        assert_eq!(
+            status,
+            GameStatus::InvalidInput {
+                input_hash: input_hash(&[0u8; 3]),
+            }
+        );
+    }
+
+    #[test]
+    fn execute_rejects_malformed_input() {
+        let executor = NativeExecutor::<BlockExecutionGame>::new();
+
+        assert!(matches!(
+            executor.execute(&[0u8; 3]),
+            Err(Error::FailedToDeserializeInput(_))
+        ));
+    }
+}
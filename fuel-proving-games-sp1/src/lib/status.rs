@@ -0,0 +1,293 @@
+//! Status mode, where a game guest commits the status of the game instead of aborting on invalid
+//! input, so that the invalidity of an input can be proven as well as its validity.
+
+use crate::common::{
+    open_envelope, GameConfig, GameExecutor, GameProver, PayloadKind, ProvingMode,
+};
+use crate::{Error, Result};
+use alloy_sol_types::SolType;
+use fuel_game_envelope_sp1::STATUS_ENVELOPE_VERSION;
+use sp1_prover::components::CpuProverComponents;
+use sp1_sdk::{ExecutionReport, Prover, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
+use std::ops::Deref;
+
+pub use fuel_game_envelope_sp1::{input_hash, StatusCode, StatusEnvelope};
+
+/// A game whose guest can commit a [`StatusEnvelope`] instead of aborting on invalid input
+pub trait StatusGame: GameConfig {
+    /// Build the zkVM stdin from raw input bytes, telling the guest whether to commit its status
+    fn status_stdin(input: &[u8], commit_status: bool) -> SP1Stdin {
+        let mut stdin = SP1Stdin::new();
+        stdin.write_slice(input);
+        stdin.write(&commit_status);
+        stdin
+    }
+}
+
+/// The status of a game, decoded from the [`StatusEnvelope`] committed by its guest
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub enum GameStatus<T> {
+    /// The game ran successfully on the input
+    Ok {
+        /// The SHA-256 hash of the raw input
+        input_hash: [u8; 32],
+        /// The public values committed by the game
+        public_values: T,
+    },
+    /// The game rejected the input
+    InvalidInput {
+        /// The SHA-256 hash of the raw input
+        input_hash: [u8; 32],
+    },
+}
+
+impl<T> GameStatus<T> {
+    /// Get the SHA-256 hash of the raw input the game ran on
+    pub fn input_hash(&self) -> &[u8; 32] {
+        match self {
+            GameStatus::Ok { input_hash, .. } | GameStatus::InvalidInput { input_hash } => {
+                input_hash
+            }
+        }
+    }
+}
+
+/// Decode the status envelope committed by a guest running in status mode
+pub fn decode_status<G: StatusGame>(bytes: &[u8]) -> Result<GameStatus<G::PublicValues>> {
+    let payload = match open_envelope::<G>(bytes)? {
+        (_, PayloadKind::Status, payload) => payload,
+        (_, kind, _) => {
+            return Err(Error::FailedToDeserializePublicOutput(format!(
+                "{}: envelope holds a {kind:?} payload, expected a status",
+                G::NAME
+            )))
+        }
+    };
+
+    let StatusEnvelope {
+        version,
+        code,
+        input_hash,
+        public_values,
+    } = StatusEnvelope::abi_decode(&payload, false)
+        .map_err(|e| Error::FailedToDeserializePublicOutput(e.to_string()))?;
+
+    if version != STATUS_ENVELOPE_VERSION {
+        return Err(Error::FailedToDeserializePublicOutput(format!(
+            "unsupported status envelope version {version}, expected {STATUS_ENVELOPE_VERSION}"
+        )));
+    }
+
+    let input_hash = input_hash.0;
+    match StatusCode::try_from(code) {
+        Ok(StatusCode::Ok) => Ok(GameStatus::Ok {
+            input_hash,
+            public_values: G::decode_public_values(&public_values)?,
+        }),
+        Ok(StatusCode::InvalidInput) => Ok(GameStatus::InvalidInput { input_hash }),
+        Err(code) => Err(Error::FailedToDeserializePublicOutput(format!(
+            "unknown status code {code}"
+        ))),
+    }
+}
+
+/// Decode the status committed to by a proof generated in status mode
+pub fn decode_proof_status<G: StatusGame>(
+    proof: &SP1ProofWithPublicValues,
+) -> Result<GameStatus<G::PublicValues>> {
+    decode_status::<G>(proof.public_values.as_slice())
+}
+
+impl<P, G> GameProver<P, G>
+where
+    P: Deref,
+    P::Target: Prover<CpuProverComponents>,
+    G: StatusGame,
+{
+    /// Prove using raw input bytes in status mode, so that invalid inputs are proven invalid
+    /// instead of failing to prove. Unlike [`GameProver::prove`], the input isn't rejected on
+    /// the host when it doesn't decode.
+    pub fn prove_status(
+        &self,
+        input: &[u8],
+        mode: ProvingMode,
+    ) -> Result<(SP1ProofWithPublicValues, SP1VerifyingKey)> {
+        self.prove_stdin(G::status_stdin(input, true), mode)
+    }
+}
+
+impl<E, G> GameExecutor<E, G>
+where
+    E: Deref,
+    E::Target: Prover<CpuProverComponents>,
+    G: StatusGame,
+{
+    /// Execute with raw input bytes in status mode, returning the committed status and the report
+    pub fn execute_status(
+        &self,
+        input: &[u8],
+    ) -> Result<(GameStatus<G::PublicValues>, ExecutionReport)> {
+        let (public_values, report) = self.execute_stdin(G::status_stdin(input, true))?;
+        Ok((decode_status::<G>(public_values.as_slice())?, report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_execution_game::BlockExecutionGame;
+    use crate::common::{defaults, game_id, Domain};
+    use crate::decompression_game::{DecompressionGame, PublicValues};
+    use fuel_game_envelope_sp1::PublicValuesEnvelope;
+    use fuel_zkvm_primitives_prover::games::decompression_game::PublicValuesStruct;
+    use fuel_zkvm_primitives_test_fixtures::{block_execution_fixtures, decompression_fixtures};
+
+    /// Run the guest of the game in status mode on an input it can't decode
+    fn assert_guest_reports_invalid_input<G: StatusGame>() {
+        let input = [0u8; 3];
+        let (status, _) = defaults::game_executor::<G>()
+            .execute_status(&input)
+            .unwrap();
+
+        assert_eq!(
+            status,
+            GameStatus::InvalidInput {
+                input_hash: input_hash(&input),
+            }
+        );
+    }
+
+    /// Run the guest of the game in status mode on a fixture, checking it commits the public
+    /// values it commits outside of status mode
+    fn assert_guest_reports_valid_input<G: StatusGame>(fixture: G::Fixture) {
+        let executor = defaults::game_executor::<G>();
+        let input = G::get_fixture_input(&fixture);
+
+        let (public_values, _) = executor.execute(&input).unwrap();
+        let (status, _) = executor.execute_status(&input).unwrap();
+        assert_eq!(
+            status,
+            GameStatus::Ok {
+                input_hash: input_hash(&input),
+                public_values,
+            }
+        );
+    }
+
+    #[test]
+    fn block_execution_guest_reports_invalid_input() {
+        assert_guest_reports_invalid_input::<BlockExecutionGame>();
+    }
+
+    #[test]
+    fn block_execution_guest_reports_valid_input() {
+        let fixture = block_execution_fixtures::fixtures::all_fixtures()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_guest_reports_valid_input::<BlockExecutionGame>(fixture);
+    }
+
+    #[test]
+    fn decompression_guest_reports_invalid_input() {
+        assert_guest_reports_invalid_input::<DecompressionGame>();
+    }
+
+    #[test]
+    fn decompression_guest_reports_valid_input() {
+        let fixture = decompression_fixtures::all_fixtures()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_guest_reports_valid_input::<DecompressionGame>(fixture);
+    }
+
+    fn seal(status: &StatusEnvelope) -> Vec<u8> {
+        PublicValuesEnvelope::seal(
+            game_id::DECOMPRESSION,
+            Domain::default(),
+            PayloadKind::Status,
+            StatusEnvelope::abi_encode(status),
+        )
+    }
+
+    fn envelope(code: u8, public_values: Vec<u8>) -> Vec<u8> {
+        seal(&StatusEnvelope {
+            version: STATUS_ENVELOPE_VERSION,
+            code,
+            input_hash: input_hash(b"input").into(),
+            public_values: public_values.into(),
+        })
+    }
+
+    #[test]
+    fn decode_status_of_valid_input() {
+        let public_values = PublicValuesStruct::abi_encode(&PublicValuesStruct {
+            first_block_height: 1u64.into(),
+            last_block_height: 2u64.into(),
+        });
+
+        let status =
+            decode_status::<DecompressionGame>(&envelope(StatusCode::Ok as u8, public_values))
+                .unwrap();
+        assert_eq!(
+            status,
+            GameStatus::Ok {
+                input_hash: input_hash(b"input"),
+                public_values: PublicValues {
+                    first_block_height: 1,
+                    last_block_height: 2,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn decode_status_of_invalid_input() {
+        let status =
+            decode_status::<DecompressionGame>(&envelope(StatusCode::InvalidInput as u8, vec![]))
+                .unwrap();
+        assert_eq!(
+            status,
+            GameStatus::InvalidInput {
+                input_hash: input_hash(b"input"),
+            }
+        );
+    }
+
+    #[test]
+    fn decode_status_rejects_unknown_codes_and_versions() {
+        let result = decode_status::<DecompressionGame>(&envelope(7, vec![]));
+        assert!(matches!(
+            result,
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+
+        let bytes = seal(&StatusEnvelope {
+            version: STATUS_ENVELOPE_VERSION + 1,
+            code: StatusCode::InvalidInput as u8,
+            input_hash: input_hash(b"input").into(),
+            public_values: Default::default(),
+        });
+        let result = decode_status::<DecompressionGame>(&bytes);
+        assert!(matches!(
+            result,
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+    }
+
+    #[test]
+    fn decode_status_rejects_public_values_envelopes() {
+        let bytes = PublicValuesEnvelope::seal(
+            game_id::DECOMPRESSION,
+            Domain::default(),
+            PayloadKind::PublicValues,
+            vec![],
+        );
+        let result = decode_status::<DecompressionGame>(&bytes);
+        assert!(matches!(
+            result,
+            Err(Error::FailedToDeserializePublicOutput(_))
+        ));
+    }
+}